@@ -1,11 +1,32 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::collections::HashSet;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data));
-    println!("Part 2: {}", part_two(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data)
+    }
 }
 
 fn part_one(data: &str) -> usize {
@@ -1,9 +1,38 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data));
-    println!("Part 2: {}", part_two(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => iterate_length(data, 5).to_string(),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
 }
 
 fn part_one(data: &str) -> usize {
@@ -1,13 +1,32 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
 use itertools::Itertools;
 
+pub const INPUT: &str = "vzbxkghb";
+
 pub fn main() {
-    print!("Part 1: ");
-    print_password(part_one("vzbxkghb"));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = String;
+    type PartTwo = String;
 
-    print!("Part 2: ");
-    print_password(part_two("vzbxkghb"));
+    fn parse(data: &'static str) -> Self::Parsed {
+        data.trim()
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        password_to_string(part_one(data))
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        password_to_string(part_two(data))
+    }
 }
 
 fn part_one(data: &str) -> Password {
@@ -31,14 +50,13 @@ fn next_valid_password(mut password: Password) -> Password {
     password
 }
 
-fn print_password(password: Password) {
-    for elem in password.into_iter().rev() {
-        if elem != 0 {
-            let c = char::from_u32(u32::from(elem)).unwrap();
-            print!("{c}");
-        }
-    }
-    println!();
+fn password_to_string(password: Password) -> String {
+    password
+        .into_iter()
+        .rev()
+        .filter(|&elem| elem != 0)
+        .map(char::from)
+        .collect()
 }
 
 fn new_password(data: &str) -> Password {
@@ -1,10 +1,38 @@
 #![warn(clippy::all, clippy::pedantic)]
+use aoc_solution::Solution;
 use itertools::Itertools;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<8>(data));
-    println!("Part 2: {}", part_two::<8>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<8>(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<8>(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<4>(data).to_string(),
+            _ => part_two::<4>(data).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(data: &str) -> i32 {
@@ -1,10 +1,38 @@
 #![warn(clippy::all, clippy::pedantic)]
+use aoc_solution::Solution;
 use itertools::Itertools;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data, 2_503));
-    println!("Part 2: {}", part_two(data, 2_503));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data, 2_503)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data, 2_503)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one(data, 1_000).to_string(),
+            _ => part_two(data, 1_000).to_string(),
+        }
+    }
 }
 
 fn part_one(data: &str, time: u32) -> u32 {
@@ -1,8 +1,30 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
+
+/// The ingredients from my input are baked into the maths, so there's nothing to read in
+pub const INPUT: &str = "";
+
 pub fn main() {
-    println!("Part 1: {}", part_one());
-    println!("Part 2: {}", part_two());
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(_data: &'static str) -> Self::Parsed {}
+
+    fn part_one((): &mut Self::Parsed) -> Self::PartOne {
+        part_one()
+    }
+
+    fn part_two((): &mut Self::Parsed) -> Self::PartTwo {
+        part_two()
+    }
 }
 
 fn part_one() -> i64 {
@@ -1,11 +1,39 @@
 #![warn(clippy::all, clippy::pedantic)]
+use aoc_solution::Solution;
 use itertools::Itertools;
 use std::cmp::Ordering;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<20>(data, 150));
-    println!("Part 2: {}", part_two::<20>(data, 150));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<20>(data, 150)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<20>(data, 150)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<5>(data, 25).to_string(),
+            _ => part_two::<5>(data, 25).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(data: &str, target: u8) -> u32 {
@@ -1,9 +1,38 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<100>(data));
-    println!("Part 2: {}", part_two::<100>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<100>(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<100>(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<6>(data).to_string(),
+            _ => part_two::<6>(data).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(data: &str) -> u16 {
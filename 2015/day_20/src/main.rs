@@ -1,8 +1,31 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = "29000000";
+
 pub fn main() {
-    println!("Part 1: {}", part_one(29_000_000 / 10));
-    println!("Part 2: {}", part_two(29_000_000 / 11));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = u32;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data.trim().parse().unwrap()
+    }
+
+    fn part_one(&mut target: &mut Self::Parsed) -> Self::PartOne {
+        part_one(target / 10)
+    }
+
+    fn part_two(&mut target: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(target / 11)
+    }
 }
 
 fn part_one(lim: u32) -> u32 {
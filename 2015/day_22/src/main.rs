@@ -1,9 +1,38 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data, 50, 500));
-    println!("Part 2: {}", part_two(data, 50, 500));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data, 50, 500)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data, 50, 500)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one(data, 10, 250).to_string(),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
 }
 
 fn part_one(data: &str, hp: u8, mana: u32) -> u32 {
@@ -1,15 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use itertools::Itertools;
 use md5::{Digest, Md5};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt")
-        .trim_end_matches('\n')
-        .trim_end_matches('\r');
-    let hashes = find_hashes(data);
-    println!("Part 1: {}", part_one(hashes));
-    println!("Part 2: {}", part_two(hashes));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Hashes;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let data = data.trim_end_matches('\n').trim_end_matches('\r');
+        find_hashes(data)
+    }
+
+    fn part_one(hashes: &mut Self::Parsed) -> Self::PartOne {
+        part_one(*hashes)
+    }
+
+    fn part_two(hashes: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(*hashes)
+    }
 }
 
 fn part_one(data: Hashes) -> String {
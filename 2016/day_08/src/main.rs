@@ -1,11 +1,38 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
-use std::fmt::Display;
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
 
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<6, 50>(data));
-    println!("Part 2: {}", part_two::<6, 50>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = u32;
+    type PartTwo = aoc_solution::Screen;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<6, 50>(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<6, 50>(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<3, 7>(data).to_string(),
+            _ => part_two::<3, 7>(data).to_string(),
+        }
+    }
 }
 
 fn part_one<const R: usize, const C: usize>(data: &str) -> u32 {
@@ -16,13 +43,12 @@ fn part_one<const R: usize, const C: usize>(data: &str) -> u32 {
     screen.count_on()
 }
 
-fn part_two<const R: usize, const C: usize>(data: &str) -> u32 {
+fn part_two<const R: usize, const C: usize>(data: &str) -> aoc_solution::Screen {
     let mut screen = Screen::<R, C>::new();
     for instruction in data.lines() {
         screen.step(instruction);
     }
-    println!("{screen}");
-    0
+    screen.pixels()
 }
 
 struct Screen<const R: usize, const C: usize> {
@@ -107,23 +133,14 @@ impl<const R: usize, const C: usize> Screen<R, C> {
     fn count_on(&self) -> u32 {
         self.data.iter().map(|&row| row.count_ones()).sum()
     }
-}
 
-impl<const R: usize, const C: usize> Display for Screen<R, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.data {
-            let mut temp = *row;
-            for _ in 0..C {
-                if temp & 1 == 1 {
-                    write!(f, "█")?;
-                } else {
-                    write!(f, " ")?;
-                }
-                temp >>= 1;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+    /// The screen as the shared type, which can read the letters off it
+    fn pixels(&self) -> aoc_solution::Screen {
+        aoc_solution::Screen::from_rows(
+            self.data
+                .iter()
+                .map(|&row| (0..C).map(move |col| (row >> col) & 1 == 1)),
+        )
     }
 }
 
@@ -141,6 +158,9 @@ mod tests {
     #[test]
     fn two() {
         let data = include_str!("test.txt");
-        assert_eq!(0, part_two::<3, 7>(data));
+        assert_eq!(
+            ".#..#.#\n#.#....\n.#.....",
+            part_two::<3, 7>(data).to_string()
+        );
     }
 }
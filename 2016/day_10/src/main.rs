@@ -1,13 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::str::FromStr;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let mut f = Factory::new(data);
-    f.run(data);
-    println!("Part 1: {}", part_one(&f, 61, 17));
-    println!("Part 2: {}", part_two(&f));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Factory;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let mut f = Factory::new(data);
+        f.run(data);
+        f
+    }
+
+    fn part_one(f: &mut Self::Parsed) -> Self::PartOne {
+        part_one(f, 61, 17)
+    }
+
+    fn part_two(f: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(f)
+    }
 }
 
 fn part_one(f: &Factory, chip1: usize, chip2: usize) -> usize {
@@ -24,7 +45,7 @@ fn part_two(f: &Factory) -> usize {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Factory {
+pub struct Factory {
     robots: [Robot; 210],
     outputs: [Option<Microchip>; 21],
     processing_stack: [RobotNum; 10],
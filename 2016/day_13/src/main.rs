@@ -1,10 +1,31 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::collections::{BinaryHeap, HashSet};
 
+/// The favourite number from my input is baked in as a const generic, so there's nothing to read in
+pub const INPUT: &str = "";
+
 pub fn main() {
-    println!("Part 1: {}", part_one::<31, 39, 1352>());
-    println!("Part 2: {}", part_two::<31, 39, 1352>());
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(_data: &'static str) -> Self::Parsed {}
+
+    fn part_one((): &mut Self::Parsed) -> Self::PartOne {
+        part_one::<31, 39, 1352>()
+    }
+
+    fn part_two((): &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<31, 39, 1352>()
+    }
 }
 
 fn part_one<const X: u32, const Y: u32, const M: u32>() -> u32 {
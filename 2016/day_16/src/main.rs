@@ -1,9 +1,38 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {:0>17b}", part_one(data, 272));
-    println!("Part 2: {:0>17b}", part_two(data, 35_651_584));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        format!("{:0>17b}", part_one(data, 272))
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        format!("{:0>17b}", part_two(data, 35_651_584))
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => format!("{:0>5b}", part_one(data, 20)),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
 }
 
 fn part_one(data: &str, data_size: u32) -> u32 {
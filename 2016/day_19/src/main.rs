@@ -1,11 +1,31 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
-#[allow(clippy::missing_panics_doc)]
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let data = data.trim().parse().unwrap();
-    println!("Part 1: {}", part_one(data));
-    println!("Part 2: {}", part_two(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = u32;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data.trim().parse().unwrap()
+    }
+
+    fn part_one(&mut target: &mut Self::Parsed) -> Self::PartOne {
+        part_one(target)
+    }
+
+    fn part_two(&mut target: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(target)
+    }
 }
 
 const fn part_one(target: u32) -> u32 {
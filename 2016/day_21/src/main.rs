@@ -1,11 +1,39 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{fmt::Display, str::FromStr};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<8>(data));
-    println!("Part 2: {}", part_two::<8>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = Password<8>;
+    type PartTwo = Password<8>;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<5>(data).to_string(),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
 }
 
 fn part_one<const N: usize>(data: &str) -> Password<N> {
@@ -26,7 +54,7 @@ fn part_two<const N: usize>(data: &str) -> Password<N> {
     password
 }
 
-struct Password<const N: usize>([u8; N]);
+pub struct Password<const N: usize>([u8; N]);
 
 impl<const N: usize> Password<N> {
     fn new() -> Self {
@@ -1,14 +1,41 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use itertools::Itertools;
 use std::thread;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    const N: usize = 8;
-    let data = include_str!("input.txt");
-    let w = Weights::<N>::new::<184>(data);
-    println!("Part 1: {}", part_one::<N>(&w));
-    println!("Part 2: {}", part_two::<N>(&w));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Weights<8>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        Weights::new::<184>(data)
+    }
+
+    fn part_one(w: &mut Self::Parsed) -> Self::PartOne {
+        part_one(w)
+    }
+
+    fn part_two(w: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(w)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let w = Weights::<5>::new::<12>(data);
+        match part {
+            1 => part_one(&w).to_string(),
+            _ => part_two(&w).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(weights: &Weights<N>) -> u32 {
@@ -88,7 +115,7 @@ fn min_poss_remaining<const N: usize>(
 }
 
 #[derive(Debug)]
-struct Weights<const N: usize>([[(usize, u32); N]; N]);
+pub struct Weights<const N: usize>([[(usize, u32); N]; N]);
 
 impl<const N: usize> Weights<N> {
     fn new<const C: usize>(data: &'static str) -> Self {
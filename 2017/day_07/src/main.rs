@@ -1,14 +1,42 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use program::Program;
 
 const MAX_CHILDREN: usize = 7;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let programs = Programs::<'_, 1124>::from_input(data);
-    println!("Part 1: {}", part_one::<1124>(&programs));
-    println!("Part 2: {}", part_two::<1124>(&programs));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Programs<'static, 1124>;
+    type PartOne = String;
+    type PartTwo = u16;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        Programs::from_input(data)
+    }
+
+    fn part_one(programs: &mut Self::Parsed) -> Self::PartOne {
+        part_one(programs).to_owned()
+    }
+
+    fn part_two(programs: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(programs)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let p = Programs::<'_, 13>::from_input(data);
+        match part {
+            1 => part_one(&p).to_string(),
+            _ => part_two(&p).to_string(),
+        }
+    }
 }
 
 fn part_one<'inp, const N: usize>(p: &Programs<'inp, N>) -> &'inp str {
@@ -79,7 +107,7 @@ fn get_bad_weight(children: [(&str, u16); MAX_CHILDREN]) -> Option<((&str, u16),
 type Name<'inp> = &'inp str;
 
 #[derive(Debug)]
-struct Programs<'inp, const N: usize> {
+pub struct Programs<'inp, const N: usize> {
     data: [Program<'inp>; N],
     root: Name<'inp>,
 }
@@ -1,12 +1,32 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{collections::HashMap, str::FromStr};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (reg, max) = genenerate_registers(data);
-    println!("Part 1: {}", part_one(&reg));
-    println!("Part 2: {}", part_two(max));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Registers<'static>, i32);
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        genenerate_registers(data)
+    }
+
+    fn part_one((reg, _): &mut Self::Parsed) -> Self::PartOne {
+        part_one(reg)
+    }
+
+    fn part_two(&mut (_, max): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(max)
+    }
 }
 
 fn part_one(registers: &Registers) -> i32 {
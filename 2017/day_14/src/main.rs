@@ -1,14 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::collections::HashSet;
 
 use day_2017_10::KnotHash;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let used = gen_used_hash(data);
-    println!("Part 1: {}", part_one(&used));
-    println!("Part 2: {}", part_two(used));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = HashSet<(u8, u8)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        gen_used_hash(data)
+    }
+
+    fn part_one(used: &mut Self::Parsed) -> Self::PartOne {
+        part_one(used)
+    }
+
+    fn part_two(used: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(std::mem::take(used))
+    }
 }
 
 fn part_one(used: &HashSet<(u8, u8)>) -> usize {
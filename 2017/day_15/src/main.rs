@@ -1,16 +1,36 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{
     sync::{Arc, Mutex},
     thread,
 };
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (a, b) = get_seeds(data);
-    let (a_vals, b_vals) = gen_vals(a, b);
-    println!("Part 1: {}", part_one(&a_vals, &b_vals));
-    println!("Part 2: {}", part_two(&a_vals, &b_vals));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Vec<u64>, Vec<u64>);
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (a, b) = get_seeds(data);
+        gen_vals(a, b)
+    }
+
+    fn part_one((a_vals, b_vals): &mut Self::Parsed) -> Self::PartOne {
+        part_one(a_vals, b_vals)
+    }
+
+    fn part_two((a_vals, b_vals): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(a_vals, b_vals)
+    }
 }
 
 const A_FACTOR: u64 = 16_807;
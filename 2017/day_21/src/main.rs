@@ -5,6 +5,7 @@ mod grid2x2;
 mod grid3;
 mod grid_map;
 
+use aoc_solution::Solution;
 use crate::grid3::Grid3;
 use crate::grid_map::GridMap;
 use itertools::Itertools;
@@ -15,16 +16,29 @@ use itertools::Itertools;
 // # # #
 const START: u16 = 0b0000_0000_0101_1110;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    match GridMap::new(data) {
-        Ok(g) => {
-            println!("Part 1: {}", part_one(&g));
-            println!("Part 2: {}", part_two(&g));
-        }
-        Err(e) => {
-            println!("Cannot parse the input data: {e}");
-        }
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = GridMap;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        GridMap::new(data).unwrap_or_else(|e| panic!("Cannot parse the input data: {e}"))
+    }
+
+    fn part_one(grid_map: &mut Self::Parsed) -> Self::PartOne {
+        part_one(grid_map)
+    }
+
+    fn part_two(grid_map: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(grid_map)
     }
 }
 
@@ -1,12 +1,33 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
+
 const CAP_BIT: u8 = 0b0010_0000; // 32
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let reduced = reduced_polymer(data.trim().as_bytes().iter().copied());
-    println!("Part 1: {}", part_one(&reduced));
-    println!("Part 2: {}", part_two(&reduced));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        reduced_polymer(data.trim().as_bytes().iter().copied())
+    }
+
+    fn part_one(reduced: &mut Self::Parsed) -> Self::PartOne {
+        part_one(reduced)
+    }
+
+    fn part_two(reduced: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(reduced)
+    }
 }
 
 const fn part_one(reduced: &[u8]) -> usize {
@@ -1,9 +1,30 @@
+use aoc_solution::Solution;
 use stacks::{CraneMove, Stacks};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data).unwrap());
-    println!("Part 2: {}", part_two(data).unwrap());
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data).unwrap()
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data).unwrap()
+    }
 }
 
 fn part_one(data: &str) -> color_eyre::Result<String> {
@@ -1,10 +1,30 @@
+use aoc_solution::Solution;
 use itertools::Itertools;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let sizes = get_sizes(data);
-    println!("Part 1: {}", part_one(&sizes));
-    println!("Part 2: {}", part_two(&sizes));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = [u64; 200];
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        get_sizes(data)
+    }
+
+    fn part_one(sizes: &mut Self::Parsed) -> Self::PartOne {
+        part_one(sizes)
+    }
+
+    fn part_two(sizes: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(sizes)
+    }
 }
 
 fn part_one(sizes: &[u64; 200]) -> u64 {
@@ -1,11 +1,32 @@
+use aoc_solution::Solution;
 use std::fmt::{self, Display, Formatter};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (map, size) = get_map(data);
-    let visible = look_in(&map, size);
-    println!("Part 1: {}", part_one(&visible));
-    println!("Part 2: {}", part_two(&map, &visible, size, 8));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Map<u8>, Map<bool>, usize);
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (map, size) = get_map(data);
+        let visible = look_in(&map, size);
+        (map, visible, size)
+    }
+
+    fn part_one((_, visible, _): &mut Self::Parsed) -> Self::PartOne {
+        part_one(visible)
+    }
+
+    fn part_two((map, visible, size): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(map, visible, *size, 8)
+    }
 }
 
 fn part_one(visible: &Map<bool>) -> usize {
@@ -1,10 +1,30 @@
+use aoc_solution::Solution;
 use std::cmp::Ordering;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (child, tail) = compute_visited(data);
-    println!("Part 1: {}", part_one(&child));
-    println!("Part 2: {}", part_two(&tail));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Map, Map);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        compute_visited(data)
+    }
+
+    fn part_one((child, _): &mut Self::Parsed) -> Self::PartOne {
+        part_one(child)
+    }
+
+    fn part_two((_, tail): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(tail)
+    }
 }
 
 fn part_one(visited: &Map) -> usize {
@@ -1,7 +1,29 @@
+use aoc_solution::{Screen, Solution};
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one(data));
-    println!("Part 2: {}", part_two(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = i32;
+    type PartTwo = Screen;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data)
+    }
 }
 
 fn part_one(data: &str) -> i32 {
@@ -33,8 +55,8 @@ fn part_one(data: &str) -> i32 {
     ans
 }
 
-fn part_two(data: &str) -> i32 {
-    let mut screen: Screen = [[false; 40]; 6];
+fn part_two(data: &str) -> Screen {
+    let mut screen = Screen::new(40, 6);
     let mut cycle = 0;
     let mut x_reg = 1;
     for line in data.lines() {
@@ -54,8 +76,7 @@ fn part_two(data: &str) -> i32 {
             _ => unreachable!(),
         }
     }
-    screen.print();
-    0
+    screen
 }
 
 fn update_screen(cycle: i32, x_reg: i32, screen: &mut Screen) {
@@ -64,28 +85,7 @@ fn update_screen(cycle: i32, x_reg: i32, screen: &mut Screen) {
     if (x_reg - 1..=x_reg + 1).contains(&(col)) {
         let row: usize = row.try_into().unwrap();
         let col: usize = col.try_into().unwrap();
-        screen[row][col] = true;
-    }
-}
-
-trait PrintScreen {
-    fn print(&self);
-}
-
-type Screen = [[bool; 40]; 6];
-
-impl PrintScreen for Screen {
-    fn print(&self) {
-        for row in self {
-            for elem in row {
-                if *elem {
-                    print!("#");
-                } else {
-                    print!(" ");
-                }
-            }
-            println!();
-        }
+        screen.set(col, row, true);
     }
 }
 
@@ -103,6 +103,13 @@ mod tests {
     #[test]
     fn two() {
         let data = include_str!("test.txt");
-        assert_eq!(0, part_two(data));
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(expected, part_two(data).to_string());
     }
 }
@@ -1,10 +1,30 @@
+use aoc_solution::Solution;
 use itertools::Itertools;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (monkey_data, items) = parse_monkeys(data);
-    println!("Part 1: {}", part_one(&monkey_data, items));
-    println!("Part 2: {}", part_two(&monkey_data, items));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Data, Items);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        parse_monkeys(data)
+    }
+
+    fn part_one((monkey_data, items): &mut Self::Parsed) -> Self::PartOne {
+        part_one(monkey_data, *items)
+    }
+
+    fn part_two((monkey_data, items): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(monkey_data, *items)
+    }
 }
 
 fn part_one(data: &Data, items: Items) -> u64 {
@@ -73,7 +93,7 @@ enum Operation {
     Sqr,
 }
 
-struct Data {
+pub struct Data {
     operations: [Operation; 8],
     tests: [u64; 8],
     next: [(usize, usize); 8],
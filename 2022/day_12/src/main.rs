@@ -1,11 +1,39 @@
+use aoc_solution::Solution;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (map, start, end) = parse_input::<41, 83>(data);
-    println!("Part 1: {}", part_one::<41, 83>(&map, start, end));
-    println!("Part 2: {}", part_two::<41, 83>(&map, end));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Map<u8, 41, 83>, Point, Point);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        parse_input::<41, 83>(data)
+    }
+
+    fn part_one(&mut (ref map, start, end): &mut Self::Parsed) -> Self::PartOne {
+        part_one::<41, 83>(map, start, end)
+    }
+
+    fn part_two(&mut (ref map, _, end): &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<41, 83>(map, end)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let (map, start, end) = parse_input::<5, 8>(data);
+        match part {
+            1 => part_one(&map, start, end).to_string(),
+            _ => part_two(&map, end).to_string(),
+        }
+    }
 }
 
 fn part_one<const R: usize, const C: usize>(map: &Map<u8, R, C>, start: Point, end: Point) -> u32 {
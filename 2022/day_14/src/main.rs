@@ -1,9 +1,30 @@
+use aoc_solution::Solution;
 use std::cmp::{max, min};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<200, 100>(data));
-    println!("Part 2: {}", part_two::<200, 600>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<200, 100>(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<200, 600>(data)
+    }
 }
 
 fn part_one<const R: usize, const C: usize>(data: &str) -> usize {
@@ -18,7 +39,7 @@ fn part_one<const R: usize, const C: usize>(data: &str) -> usize {
 fn part_two<const R: usize, const C: usize>(data: &str) -> usize {
     let mut m = Map::<R, C>::new(data);
     let mut i = 0_usize;
-    while m.add_sand().unwrap_or(Point(0, 0)) != Point(INPUT, 0) {
+    while m.add_sand().unwrap_or(Point(0, 0)) != Point(SAND_SOURCE, 0) {
         i += 1;
     }
     i + 1
@@ -34,7 +55,7 @@ struct Map<const R: usize, const C: usize> {
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 struct Point(usize, usize);
 
-const INPUT: usize = 500;
+const SAND_SOURCE: usize = 500;
 
 impl<const R: usize, const C: usize> Map<R, C> {
     fn new(data: &str) -> Self {
@@ -61,8 +82,8 @@ impl<const R: usize, const C: usize> Map<R, C> {
         }
         Self::add_line(
             &mut map,
-            Point(INPUT - C / 2, max_depth + 2),
-            Point(INPUT + C / 2, max_depth + 2),
+            Point(SAND_SOURCE - C / 2, max_depth + 2),
+            Point(SAND_SOURCE + C / 2, max_depth + 2),
         );
         Map {
             data: map,
@@ -71,7 +92,7 @@ impl<const R: usize, const C: usize> Map<R, C> {
     }
 
     fn add_line(map: &mut [[bool; C]; R], start: Point, end: Point) {
-        let x_offset = INPUT - C / 2;
+        let x_offset = SAND_SOURCE - C / 2;
         if start.0 == end.0 {
             for row in map
                 .iter_mut()
@@ -93,15 +114,15 @@ impl<const R: usize, const C: usize> Map<R, C> {
     }
 
     fn get(&self, loc: Point) -> bool {
-        self.data[loc.1][loc.0 - (INPUT - C / 2)]
+        self.data[loc.1][loc.0 - (SAND_SOURCE - C / 2)]
     }
 
     fn set(&mut self, loc: Point) {
-        self.data[loc.1][loc.0 - (INPUT - C / 2)] = true
+        self.data[loc.1][loc.0 - (SAND_SOURCE - C / 2)] = true
     }
 
     fn add_sand(&mut self) -> Option<Point> {
-        let mut loc = Point(INPUT, 0);
+        let mut loc = Point(SAND_SOURCE, 0);
         while let Some(p) = self.drop_one(loc) {
             loc = p;
             if loc.1 == self.floor - 1 {
@@ -1,11 +1,31 @@
+use aoc_solution::Solution;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (flows, map) = parse_input(data);
-    println!("Part 1: {}", part_one(&flows, &map));
-    println!("Part 2: {}", part_two(&flows, &map));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Flows, Map);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        parse_input(data)
+    }
+
+    fn part_one((flows, map): &mut Self::Parsed) -> Self::PartOne {
+        part_one(flows, map)
+    }
+
+    fn part_two((flows, map): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(flows, map)
+    }
 }
 
 fn part_one(flows: &Flows, map: &Map) -> u32 {
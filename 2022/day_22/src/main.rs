@@ -1,9 +1,37 @@
+use aoc_solution::Solution;
 use std::fmt::Display;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<200, 150>(data, false));
-    println!("Part 2: {}", part_two::<200, 150>(data, false));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<200, 150>(data, false)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<200, 150>(data, false)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        match part {
+            1 => part_one::<12, 16>(data, true).to_string(),
+            _ => part_two::<12, 16>(data, true).to_string(),
+        }
+    }
 }
 
 fn part_one<const R: usize, const C: usize>(data: &'static str, test: bool) -> usize {
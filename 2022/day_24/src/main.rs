@@ -1,22 +1,59 @@
+use aoc_solution::Solution;
 use std::{
     collections::{BinaryHeap, HashSet},
     fmt::Display,
 };
 
+pub const INPUT: &str = include_str!("input.txt");
+
+const N: usize = 2_963;
+const R: usize = 26;
+const C: usize = 121;
+const T: usize = 300;
+
 pub fn main() {
-    const N: usize = 2_963;
-    const R: usize = 26;
-    const C: usize = 121;
-    const T: usize = 300;
+    aoc_solution::print::<Day>(INPUT);
+}
 
-    let data = include_str!("input.txt");
-    let blizzards = Blizzards::<N, R, C, T>::new(data);
-    let (steps, state) = part_one::<N, R, C, T>(blizzards);
-    println!("Part 1: {}", steps);
+pub struct Day;
 
-    let blizzards = Blizzards::<N, R, C, T>::from_state(state);
-    let steps_2 = part_two::<N, R, C, T>(blizzards);
-    println!("Part 2: {}", steps + steps_2);
+impl Solution for Day {
+    /// The steps taken so far, & where the blizzards have got to by then
+    type Parsed = (usize, [Blizzard; N]);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        (0, Blizzards::<N, R, C, T>::new(data).current)
+    }
+
+    fn part_one((steps, state): &mut Self::Parsed) -> Self::PartOne {
+        let blizzards = Blizzards::<N, R, C, T>::from_state(*state);
+        (*steps, *state) = part_one::<N, R, C, T>(blizzards);
+        *steps
+    }
+
+    fn part_two((steps, state): &mut Self::Parsed) -> Self::PartTwo {
+        // The trip back & forth picks up where the first trip left the blizzards
+        let blizzards = Blizzards::<N, R, C, T>::from_state(*state);
+        *steps + part_two::<N, R, C, T>(blizzards)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        // The trip back & forth needs room for more minutes of blizzards than the first trip alone
+        match part {
+            1 => {
+                let blizzards = Blizzards::<19, 5, 7, 19>::new(data);
+                part_one(blizzards).0.to_string()
+            }
+            _ => {
+                let blizzards = Blizzards::<19, 5, 7, 24>::new(data);
+                let (steps, state) = part_one(blizzards);
+                let steps_2 = part_two(Blizzards::<19, 5, 7, 24>::from_state(state));
+                (steps + steps_2).to_string()
+            }
+        }
+    }
 }
 
 fn part_one<const N: usize, const R: usize, const C: usize, const T: usize>(
@@ -254,7 +291,7 @@ impl<const N: usize, const R: usize, const C: usize, const T: usize> Blizzards<N
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Blizzard {
+pub struct Blizzard {
     location: Point,
     direction: Direction,
 }
@@ -1,11 +1,40 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
-#[allow(clippy::large_stack_frames)]
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (parts, gears) = find_parts::<140>(data.as_bytes());
-    println!("Part 1: {parts}");
-    println!("Part 2: {}", part_two(gears));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (u32, [[GearParts; 140]; 140]);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    #[allow(clippy::large_stack_frames)]
+    fn parse(data: &'static str) -> Self::Parsed {
+        find_parts::<140>(data.as_bytes())
+    }
+
+    fn part_one((parts, _): &mut Self::Parsed) -> Self::PartOne {
+        *parts
+    }
+
+    fn part_two((_, gears): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(*gears)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let (parts, gears) = find_parts::<10>(data.as_bytes());
+        match part {
+            1 => parts.to_string(),
+            _ => part_two(gears).to_string(),
+        }
+    }
 }
 
 fn part_two<const R: usize>(gears: [[GearParts; R]; R]) -> u32 {
@@ -95,7 +124,7 @@ fn check_for_gear<const R: usize>(
 }
 
 #[derive(Clone, Copy)]
-enum GearParts {
+pub enum GearParts {
     None,
     One(u32),
     Two((u32, u32)),
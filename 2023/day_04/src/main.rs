@@ -1,11 +1,33 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let mut cards = Vec::with_capacity(250);
-    cards.extend(data.lines().map(score_scratchcard));
-    println!("Part 1: {}", part_one(&cards));
-    println!("Part 2: {}", part_two(&cards));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<usize>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let mut cards = Vec::with_capacity(250);
+        cards.extend(data.lines().map(score_scratchcard));
+        cards
+    }
+
+    fn part_one(cards: &mut Self::Parsed) -> Self::PartOne {
+        part_one(cards)
+    }
+
+    fn part_two(cards: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(cards)
+    }
 }
 
 fn part_one(cards: &[usize]) -> u32 {
@@ -1,14 +1,33 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use itertools::Itertools;
 use std::str::FromStr;
 
-#[allow(clippy::missing_panics_doc)]
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let mut game = data.parse::<CamelCards>().unwrap();
-    println!("Part 1: {}", part_one(&mut game));
-    println!("Part 2: {}", part_two(&mut game));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = CamelCards;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data.parse().unwrap()
+    }
+
+    fn part_one(game: &mut Self::Parsed) -> Self::PartOne {
+        part_one(game)
+    }
+
+    fn part_two(game: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(game)
+    }
 }
 
 fn part_one(game: &mut CamelCards) -> u32 {
@@ -20,8 +39,8 @@ fn part_two(game: &mut CamelCards) -> u32 {
     game.score()
 }
 
-#[derive(Debug)]
-struct CamelCards {
+#[derive(Debug, Clone)]
+pub struct CamelCards {
     data: Vec<(Hand, u32)>,
 }
 
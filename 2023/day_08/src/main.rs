@@ -1,14 +1,33 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use itertools::Itertools;
 
-#[allow(clippy::missing_panics_doc)]
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (instructions, map) = data.split_once("\n\n").unwrap();
-    let mut net = Network::<666>::new(map);
-    println!("Part 1: {}", part_one::<666>(&mut net, instructions));
-    println!("Part 2: {}", part_two::<666>(&net, instructions));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (&'static str, Network<'static, 666>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (instructions, map) = data.split_once("\n\n").unwrap();
+        (instructions, Network::<666>::new(map))
+    }
+
+    fn part_one((instructions, net): &mut Self::Parsed) -> Self::PartOne {
+        part_one::<666>(net, instructions)
+    }
+
+    fn part_two((instructions, net): &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<666>(net, instructions)
+    }
 }
 
 fn part_one<const N: usize>(net: &mut Network<N>, instructions: &str) -> usize {
@@ -28,8 +47,8 @@ fn part_two<const N: usize>(net: &Network<N>, instructions: &str) -> usize {
         .fold(1, |acc, v| v * (acc / gcd(acc, v)))
 }
 
-#[derive(Debug)]
-struct Network<'a, const N: usize> {
+#[derive(Debug, Clone)]
+pub struct Network<'a, const N: usize> {
     data: [(&'a str, usize, usize); N],
     current_node: (&'a str, usize),
 }
@@ -1,26 +1,68 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::fmt::Display;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    const R: usize = 140;
-    const C: usize = 140;
-    let data = include_str!("input.txt");
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Landscape<'static, 140, 140>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let mut l = Landscape::<140, 140>::new(data);
+        l.run();
+        l
+    }
+
+    fn part_one(l: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<140, 140>(l)
+    }
+
+    fn part_two(l: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<140, 140>(l)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        // The examples come in a few sizes, & the landscape has to be sized to fit each one
+        let rows = data.lines().count();
+        let cols = data.lines().next().map_or(0, str::len);
+        match (rows, cols) {
+            (5, 5) => solve_example::<5, 5>(data, part),
+            (9, 10) => solve_example::<9, 10>(data, part),
+            (9, 11) => solve_example::<9, 11>(data, part),
+            (10, 20) => solve_example::<10, 20>(data, part),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
+}
+
+fn solve_example<const R: usize, const C: usize>(data: &'static str, part: u8) -> String {
     let mut l = Landscape::<R, C>::new(data);
     l.run();
-    println!("Part 1: {}", part_one::<R, C>(&l));
-    println!("Part 2: {}", part_two::<R, C>(l));
+    match part {
+        1 => part_one(&l).to_string(),
+        _ => part_two(&mut l).to_string(),
+    }
 }
 
 fn part_one<const R: usize, const C: usize>(l: &Landscape<'_, R, C>) -> u32 {
     l.steps / 2
 }
 
-fn part_two<const R: usize, const C: usize>(mut l: Landscape<'_, R, C>) -> usize {
+fn part_two<const R: usize, const C: usize>(l: &mut Landscape<'_, R, C>) -> usize {
     l.count_inner()
 }
 
-struct Landscape<'a, const R: usize, const C: usize> {
+#[derive(Clone)]
+pub struct Landscape<'a, const R: usize, const C: usize> {
     data: &'a [u8],
     mapped: [[Option<MapType>; C]; R],
     location: usize,
@@ -388,7 +430,7 @@ mod tests {
         let data = include_str!("test_a.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(1, part_two::<R, C>(l));
+        assert_eq!(1, part_two::<R, C>(&mut l));
     }
 
     #[test]
@@ -398,7 +440,7 @@ mod tests {
         let data = include_str!("test_b.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(1, part_two::<R, C>(l));
+        assert_eq!(1, part_two::<R, C>(&mut l));
     }
 
     #[test]
@@ -408,7 +450,7 @@ mod tests {
         let data = include_str!("test_c.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(4, part_two::<R, C>(l));
+        assert_eq!(4, part_two::<R, C>(&mut l));
     }
 
     #[test]
@@ -418,7 +460,7 @@ mod tests {
         let data = include_str!("test_d.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(4, part_two::<R, C>(l));
+        assert_eq!(4, part_two::<R, C>(&mut l));
     }
 
     #[test]
@@ -428,7 +470,7 @@ mod tests {
         let data = include_str!("test_e.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(8, part_two::<R, C>(l));
+        assert_eq!(8, part_two::<R, C>(&mut l));
     }
 
     #[test]
@@ -438,6 +480,6 @@ mod tests {
         let data = include_str!("test_f.txt");
         let mut l = Landscape::<R, C>::new(data);
         l.run();
-        assert_eq!(10, part_two::<R, C>(l));
+        assert_eq!(10, part_two::<R, C>(&mut l));
     }
 }
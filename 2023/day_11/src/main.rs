@@ -1,14 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::usize;
 
 use itertools::Itertools;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let s = Sky::new(data);
-    println!("Part 1: {}", part_one(&s));
-    println!("Part 2: {}", part_two(&s, 999_999));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Sky;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        Sky::new(data)
+    }
+
+    fn part_one(s: &mut Self::Parsed) -> Self::PartOne {
+        part_one(s)
+    }
+
+    fn part_two(s: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(s, 999_999)
+    }
 }
 
 fn part_one(s: &Sky) -> usize {
@@ -20,7 +40,7 @@ fn part_two(s: &Sky, expand_by: usize) -> usize {
 }
 
 #[derive(Debug)]
-struct Sky {
+pub struct Sky {
     galaxies: Vec<Point>,
     empty_rows: Vec<usize>,
     empty_cols: Vec<usize>,
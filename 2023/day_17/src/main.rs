@@ -1,14 +1,48 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{
     collections::{BinaryHeap, HashSet},
     fmt::Display,
 };
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!("Part 1: {}", part_one::<141>(data));
-    println!("Part 2: {}", part_two::<141, 141>(data));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<141>(data)
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<141, 141>(data)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        // The examples for part 2 come in a few sizes, each of which needs its own grid
+        let rows = data.lines().count();
+        let cols = data.lines().next().map_or(0, str::len);
+        match (part, rows, cols) {
+            (1, 13, 13) => part_one::<13>(data).to_string(),
+            (2, 13, 13) => part_two::<13, 13>(data).to_string(),
+            (2, 5, 12) => part_two::<5, 12>(data).to_string(),
+            (2, 7, 19) => part_two::<7, 19>(data).to_string(),
+            _ => aoc_solution::solve_part::<Self>(data, part),
+        }
+    }
 }
 
 fn part_one<const N: usize>(data: &str) -> usize {
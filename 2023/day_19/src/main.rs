@@ -6,16 +6,45 @@ mod workflows;
 
 use crate::part::Part;
 use crate::workflows::Workflows;
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
 
-#[allow(clippy::missing_panics_doc)]
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (workflows, parts) = data.split_once("\n\n").unwrap();
-    let mut workflows: Workflows<'_, 541> = workflows.try_into().unwrap();
-    workflows.build_ranges();
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Workflows<'static, 541>, &'static str);
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (workflows, parts) = data.split_once("\n\n").unwrap();
+        let mut workflows: Workflows<'_, 541> = workflows.try_into().unwrap();
+        workflows.build_ranges();
+        (workflows, parts)
+    }
 
-    println!("Part 1: {}", part_one::<541>(&workflows, parts));
-    println!("Part 2: {}", part_two::<541>(&workflows));
+    fn part_one((workflows, parts): &mut Self::Parsed) -> Self::PartOne {
+        part_one::<541>(workflows, parts)
+    }
+
+    fn part_two((workflows, _): &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<541>(workflows)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let (workflows, parts) = data.split_once("\n\n").unwrap();
+        let mut workflows: Workflows<'_, 11> = workflows.try_into().unwrap();
+        workflows.build_ranges();
+        match part {
+            1 => part_one(&workflows, parts).to_string(),
+            _ => part_two(&workflows).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(workflows: &Workflows<'_, N>, parts: &str) -> u32 {
@@ -1,10 +1,31 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
+
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (even, odd, outer) = run(data);
-    println!("Part 1: {}", part_one(even));
-    println!("Part 2: {}", part_two(even, odd, outer));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (usize, usize, usize);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        run(data)
+    }
+
+    fn part_one((even, _, _): &mut Self::Parsed) -> Self::PartOne {
+        part_one(*even)
+    }
+
+    fn part_two((even, odd, outer): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(*even, *odd, *outer)
+    }
 }
 
 const fn part_one(even: usize) -> usize {
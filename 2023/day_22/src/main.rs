@@ -1,14 +1,40 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use stack::Stack;
 
-#[allow(clippy::missing_panics_doc)]
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    const N: usize = 1493;
-    let data = include_str!("input.txt");
-    let s: Stack<N> = data.parse().unwrap();
-    println!("Part 1: {}", part_one::<N>(&s));
-    println!("Part 2: {}", part_two::<N>(&s));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Stack<1493>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data.parse().unwrap()
+    }
+
+    fn part_one(s: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<1493>(s)
+    }
+
+    fn part_two(s: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<1493>(s)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let s: Stack<7> = data.parse().unwrap();
+        match part {
+            1 => part_one(&s).to_string(),
+            _ => part_two(&s).to_string(),
+        }
+    }
 }
 
 fn part_one<const N: usize>(s: &Stack<N>) -> usize {
@@ -1,12 +1,40 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{collections::VecDeque, thread};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let graph = Graph::new::<141>(data);
-    println!("Part 1: {}", part_one(&graph));
-    println!("Part 2: {}", part_two(&graph));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Graph;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        Graph::new::<141>(data)
+    }
+
+    fn part_one(graph: &mut Self::Parsed) -> Self::PartOne {
+        part_one(graph)
+    }
+
+    fn part_two(graph: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(graph)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let graph = Graph::new::<23>(data);
+        match part {
+            1 => part_one(&graph).to_string(),
+            _ => part_two(&graph).to_string(),
+        }
+    }
 }
 
 fn part_one(graph: &Graph) -> usize {
@@ -102,7 +130,7 @@ impl Destinations {
 }
 
 #[derive(Debug)]
-struct Graph {
+pub struct Graph {
     data: Vec<(Point, Destinations)>,
 }
 
@@ -1,16 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
 use crate::point::Point;
+use aoc_solution::Solution;
 use hail_path::HailPath;
 use segments::Solver;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    println!(
-        "Part 1: {}",
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        data
+    }
+
+    fn part_one(data: &mut Self::Parsed) -> Self::PartOne {
         part_one::<300>(data, 200_000_000_000_000, 400_000_000_000_000)
-    );
-    println!("Part 2: {}", part_two(data));
+    }
+
+    fn part_two(data: &mut Self::Parsed) -> Self::PartTwo {
+        part_two(data)
+    }
 }
 
 fn part_one<const N: usize>(data: &str, min: i128, max: i128) -> usize {
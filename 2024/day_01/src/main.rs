@@ -1,12 +1,32 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::cmp::Ordering;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (list_1, list_2) = parse_lists::<1000>(data);
-    println!("Part 1: {}", part_one(&list_1, &list_2));
-    println!("Part 2: {}", part_two(&list_1, &list_2));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = ([u32; 1000], [u32; 1000]);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        parse_lists::<1000>(data)
+    }
+
+    fn part_one((list_1, list_2): &mut Self::Parsed) -> Self::PartOne {
+        part_one(list_1, list_2)
+    }
+
+    fn part_two((list_1, list_2): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(list_1, list_2)
+    }
 }
 
 fn part_one(list_1: &[u32], list_2: &[u32]) -> u32 {
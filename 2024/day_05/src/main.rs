@@ -1,15 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 #![allow(dead_code, clippy::missing_panics_doc)]
 
+use aoc_solution::Solution;
 use std::{cmp, num::ParseIntError, str::FromStr};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (rules, pages) = data.split_once("\n\n").unwrap();
-    let r = Rules::new(rules);
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
 
-    println!("Part 1: {}", part_one(&r, pages));
-    println!("Part 2: {}", part_two(&r, pages));
+impl Solution for Day {
+    type Parsed = (Rules, &'static str);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (rules, pages) = data.split_once("\n\n").unwrap();
+        (Rules::new(rules), pages)
+    }
+
+    fn part_one((r, pages): &mut Self::Parsed) -> Self::PartOne {
+        part_one(r, pages)
+    }
+
+    fn part_two((r, pages): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(r, pages)
+    }
 }
 
 fn part_one(r: &Rules, pages: &str) -> usize {
@@ -61,7 +80,7 @@ impl Layout {
 }
 
 #[derive(Debug)]
-struct Rules {
+pub struct Rules {
     order: Vec<usize>,
 }
 
@@ -1,15 +1,34 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (ranges, ingredients) = data.split_once("\n\n").unwrap();
-    let r = Ranges::new(ranges);
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (Ranges, &'static str);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        let (ranges, ingredients) = data.split_once("\n\n").unwrap();
+        (Ranges::new(ranges), ingredients)
+    }
 
-    println!("Part 1: {}", part_one(&r, ingredients));
-    println!("Part 2: {}", part_two(&r));
+    fn part_one((r, ingredients): &mut Self::Parsed) -> Self::PartOne {
+        part_one(r, ingredients)
+    }
+
+    fn part_two((r, _): &mut Self::Parsed) -> Self::PartTwo {
+        part_two(r)
+    }
 }
 
 fn part_one(ranges: &Ranges, ingredients: &str) -> usize {
@@ -25,7 +44,7 @@ fn part_two(ranges: &Ranges) -> usize {
     ranges.good_values()
 }
 
-struct Ranges {
+pub struct Ranges {
     data: Vec<(usize, usize)>,
 }
 
@@ -1,17 +1,46 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display, str::FromStr};
 
+pub const INPUT: &str = include_str!("input.txt");
+
 pub fn main() {
-    let data = include_str!("input.txt");
-    let (points, mut edges, mut find) = prep::<1000>(data);
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = ([Point3; 1000], BinaryHeap<HeapElement>, UnionFind<1000>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        prep::<1000>(data)
+    }
+
+    fn part_one((_, edges, find): &mut Self::Parsed) -> Self::PartOne {
+        part_one::<1000>(1000, edges, find)
+    }
+
+    fn part_two((points, edges, find): &mut Self::Parsed) -> Self::PartTwo {
+        // Carries on connecting from where part one got to
+        part_two::<1000>(points, edges, find)
+    }
 
-    println!("Part 1: {}", part_one::<1000>(1000, &mut edges, &mut find));
-    println!("Part 2: {}", part_two::<1000>(&points, edges, find));
+    fn example(data: &'static str, part: u8) -> String {
+        let (points, mut edges, mut find) = prep::<20>(data);
+        let one = part_one(10, &mut edges, &mut find);
+        match part {
+            1 => one.to_string(),
+            _ => part_two(&points, &mut edges, &mut find).to_string(),
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone, Copy)]
-struct Point3 {
+pub struct Point3 {
     x: usize,
     y: usize,
     z: usize,
@@ -69,7 +98,7 @@ impl Point3 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct HeapElement {
+pub struct HeapElement {
     dist: usize,
     point_1: usize,
     point_2: usize,
@@ -102,7 +131,8 @@ impl PartialOrd for HeapElement {
     }
 }
 
-struct UnionFind<const N: usize> {
+#[derive(Clone)]
+pub struct UnionFind<const N: usize> {
     root_pointers: [usize; N],
     size: [usize; N],
     disjoint_sets: usize,
@@ -206,8 +236,8 @@ fn part_one<const N: usize>(
 
 fn part_two<const N: usize>(
     points: &[Point3],
-    mut edges: BinaryHeap<HeapElement>,
-    mut find: UnionFind<N>,
+    edges: &mut BinaryHeap<HeapElement>,
+    find: &mut UnionFind<N>,
 ) -> usize {
     while find.disjoint_sets > 1 {
         if let Some(e) = edges.pop() {
@@ -235,7 +265,7 @@ mod tests {
     #[test]
     fn two() {
         let data = include_str!("test.txt");
-        let (points, edges, find) = prep::<20>(data);
-        assert_eq!(25272, part_two::<20>(&points, edges, find));
+        let (points, mut edges, mut find) = prep::<20>(data);
+        assert_eq!(25272, part_two::<20>(&points, &mut edges, &mut find));
     }
 }
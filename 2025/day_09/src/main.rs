@@ -1,14 +1,43 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+use aoc_solution::Solution;
 use std::{cmp::Ordering, str::FromStr};
 
+pub const INPUT: &str = include_str!("input.txt");
+
+const POINTS: usize = 498;
+const LINES: usize = POINTS / 2 - 1;
+
 pub fn main() {
-    const POINTS: usize = 498;
-    const LINES: usize = POINTS / 2 - 1;
-    let data = include_str!("input.txt");
-    let tiles = prep::<POINTS, LINES>(data);
-    println!("Part 1: {}", part_one::<POINTS, LINES>(&tiles));
-    println!("Part 2: {}", part_two::<POINTS, LINES>(&tiles));
+    aoc_solution::print::<Day>(INPUT);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Tiles<POINTS, LINES>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(data: &'static str) -> Self::Parsed {
+        prep::<POINTS, LINES>(data)
+    }
+
+    fn part_one(tiles: &mut Self::Parsed) -> Self::PartOne {
+        part_one::<POINTS, LINES>(tiles)
+    }
+
+    fn part_two(tiles: &mut Self::Parsed) -> Self::PartTwo {
+        part_two::<POINTS, LINES>(tiles)
+    }
+
+    fn example(data: &'static str, part: u8) -> String {
+        let tiles = prep::<10, 4>(data);
+        match part {
+            1 => part_one(&tiles).to_string(),
+            _ => part_two(&tiles).to_string(),
+        }
+    }
 }
 
 fn prep<const N: usize, const M: usize>(data: &str) -> Tiles<N, M> {
@@ -51,7 +80,7 @@ fn part_two<const N: usize, const M: usize>(tiles: &Tiles<N, M>) -> usize {
     max
 }
 
-struct Tiles<const N: usize, const M: usize> {
+pub struct Tiles<const N: usize, const M: usize> {
     points: [Point; N],
     verticals: [Line; M],
     horizontals: [Line; M],
@@ -0,0 +1,199 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+
+//! Generates the registry of years & days from the `yyyy/day_dd` folders, & passes on the details
+//! of the build for the reports
+//!
+//! A day folder is only added to the registry if its crate is also a dependency in the root
+//! `Cargo.toml`, otherwise it can't be linked in. Any day folders that are missing the dependency
+//! are left out with a warning naming the line to add, so the rest of the days still build
+//!
+//! The puzzle titles are picked up from the links in the README's tables, so the reports can use
+//! them too
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // A day can only be registered once it's a dependency, so this catches all the new ones
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=README.md");
+
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).expect("can read Cargo.toml");
+    let deps = dependencies(&manifest);
+    let folders = day_folders(root);
+
+    let mut registry: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+    for (&year, days) in &folders {
+        // So a new day folder is picked up without waiting for Cargo.toml to change
+        println!("cargo:rerun-if-changed={year}");
+        let available = registry.entry(year).or_default();
+        for &day in days {
+            if deps.contains(&(year, day)) {
+                available.push(day);
+            } else {
+                println!(
+                    "cargo:warning={year}/day_{day:02} is left out as it isn't a dependency in \
+                     Cargo.toml. Add `day_{year}_{day:02} = {{ path = \"{year}/day_{day:02}\" }}` \
+                     to its [dependencies] to run it"
+                );
+            }
+        }
+    }
+
+    let out =
+        Path::new(&std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo")).join("registry.rs");
+    let readme = fs::read_to_string(root.join("README.md")).unwrap_or_default();
+    fs::write(out, generate(&registry, &titles(&readme))).expect("can write the registry");
+
+    build_details();
+}
+
+/// Pass on the details of the build that get recorded with each report, as they can only be
+/// known for sure while building
+fn build_details() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .ok()
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map_or_else(|| String::from("unknown"), |v| v.trim().to_owned());
+    let var = |name| std::env::var(name).unwrap_or_default();
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={version}");
+    println!("cargo:rustc-env=AOC_PROFILE={}", var("PROFILE"));
+    println!(
+        "cargo:rustc-env=AOC_TARGET_FEATURES={}",
+        var("CARGO_CFG_TARGET_FEATURE")
+    );
+}
+
+/// The (year, day) of every day crate that the root `Cargo.toml` depends on
+///
+/// Dependencies are expected one per line, e.g. `day_2015_01 = { path = "2015/day_01" }`, and
+/// commented out lines are skipped
+fn dependencies(manifest: &str) -> BTreeSet<(u16, u8)> {
+    manifest
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("day_"))
+        .filter_map(|line| {
+            let (name, _) = line.split_once('=')?;
+            let (year, day) = name.trim().split_once('_')?;
+            Some((year.parse().ok()?, day.parse().ok()?))
+        })
+        .collect()
+}
+
+/// The puzzle title of each day with a row in the README, keyed by (year, day)
+///
+/// Rows link to the day's code as `[Day 1: Not Quite Lisp](2015/day_01/src/main.rs)`
+fn titles(readme: &str) -> BTreeMap<(u16, u8), String> {
+    readme
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once("[Day ")?;
+            let (text, path) = rest.split_once("](")?;
+            let (_, title) = text.split_once(": ")?;
+            let (year, day) = path.split_once("/day_")?;
+            let day = day.get(..2)?;
+            Some((
+                (year.parse().ok()?, day.parse().ok()?),
+                title.trim().to_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Every `yyyy/day_dd` folder that holds a crate, grouped by year
+fn day_folders(root: &Path) -> BTreeMap<u16, BTreeSet<u8>> {
+    let mut out: BTreeMap<u16, BTreeSet<u8>> = BTreeMap::new();
+    let entries = fs::read_dir(root).expect("can read the root folder");
+    for year_dir in entries.filter_map(Result::ok) {
+        let name = year_dir.file_name();
+        let Some(year) = name
+            .to_str()
+            .filter(|n| n.len() == 4)
+            .and_then(|n| n.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        let Ok(entries) = fs::read_dir(year_dir.path()) else {
+            continue;
+        };
+        let days = out.entry(year).or_default();
+        for day_dir in entries.filter_map(Result::ok) {
+            let name = day_dir.file_name();
+            let Some(day) = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("day_"))
+                .and_then(|n| n.parse::<u8>().ok())
+            else {
+                continue;
+            };
+            if day_dir.path().join("Cargo.toml").exists() {
+                days.insert(day);
+            }
+        }
+    }
+    out
+}
+
+/// The source for the `Year` enum & the list of days in each year
+///
+/// Every year from the first to the last is included, even if it has no days yet. Days missing
+/// from the README get an empty title
+fn generate(registry: &BTreeMap<u16, Vec<u8>>, titles: &BTreeMap<(u16, u8), String>) -> String {
+    let (Some(&first), Some(&last)) = (registry.keys().next(), registry.keys().next_back()) else {
+        panic!("no year folders found");
+    };
+    let years = (first..=last).collect::<Vec<_>>();
+
+    let mut s = String::new();
+    s.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub enum Year {\n");
+    for y in &years {
+        let _ = writeln!(s, "    Y{y},");
+    }
+    s.push_str("}\n\n");
+
+    let _ = writeln!(s, "pub const YEARS: [Year; {}] = [", years.len());
+    for y in &years {
+        let _ = writeln!(s, "    Year::Y{y},");
+    }
+    s.push_str("];\n\n");
+
+    s.push_str("impl Year {\n    pub const fn number(self) -> u16 {\n        match self {\n");
+    for y in &years {
+        let _ = writeln!(s, "            Self::Y{y} => {y},");
+    }
+    s.push_str("        }\n    }\n\n");
+
+    s.push_str("    /// Every day that can be run in the year, in order\n");
+    s.push_str("    pub const fn days(self) -> &'static [Day] {\n        match self {\n");
+    for y in &years {
+        let _ = writeln!(s, "            Self::Y{y} => DAYS_{y},");
+    }
+    s.push_str("        }\n    }\n}\n\n");
+
+    for y in &years {
+        let days = registry.get(y).map_or(&[][..], Vec::as_slice);
+        let _ = writeln!(s, "const DAYS_{y}: &[Day] = &[");
+        for d in days {
+            let title = titles.get(&(*y, *d)).map_or("", String::as_str);
+            let _ = writeln!(s, "    day!(day_{y}_{d:02}, {d}, {title:?}),");
+        }
+        s.push_str("];\n\n");
+    }
+
+    s.push_str("impl TryFrom<u16> for Year {\n    type Error = u16;\n\n");
+    s.push_str(
+        "    fn try_from(value: u16) -> Result<Self, Self::Error> {\n        match value {\n",
+    );
+    for y in &years {
+        let _ = writeln!(s, "            {y} => Ok(Self::Y{y}),");
+    }
+    s.push_str("            _ => Err(value),\n        }\n    }\n}\n");
+    s
+}
@@ -0,0 +1,151 @@
+use sha2::{Digest, Sha256};
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Inputs that have already been downloaded, kept outside the repo so that a fresh clone or a new
+/// worktree can have them back without asking the site again
+///
+/// Each input is stored once, named by the hash of its contents. Alongside is an index of small
+/// files, one per site, year & day, that each hold the hash of that day's input
+pub struct Cache {
+    objects: PathBuf,
+    index: PathBuf,
+}
+
+impl Cache {
+    /// The cache of inputs from the site at `base_url`, kept in `dir`
+    pub fn open(dir: &Path, base_url: &str) -> Self {
+        Self {
+            objects: dir.join("objects"),
+            index: dir.join("index").join(site_folder(base_url)),
+        }
+    }
+
+    /// A day's input, if it's been cached & hasn't been changed since
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        let hash = fs::read_to_string(self.entry(year, day)).ok()?;
+        let data = fs::read_to_string(self.objects.join(hash.trim())).ok()?;
+        (hex_hash(&data) == hash.trim()).then_some(data)
+    }
+
+    pub fn put(&self, year: u16, day: u8, data: &str) -> io::Result<()> {
+        let hash = hex_hash(data);
+        let object = self.objects.join(&hash);
+        if !object.exists() {
+            fs::create_dir_all(&self.objects)?;
+            crate::save(&object, data)?;
+        }
+        let entry = self.entry(year, day);
+        if let Some(parent) = entry.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        crate::save(&entry, &hash)
+    }
+
+    fn entry(&self, year: u16, day: u8) -> PathBuf {
+        self.index
+            .join(year.to_string())
+            .join(format!("day_{day:02}"))
+    }
+}
+
+/// Where the cache lives: `$XDG_CACHE_HOME/aoc-inputs`, falling back to `~/.cache/aoc-inputs`
+///
+/// Relative paths in `XDG_CACHE_HOME` are ignored, as the XDG spec asks
+pub fn default_dir(xdg_cache_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    let base = xdg_cache_home
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| home.map(|h| PathBuf::from(h).join(".cache")))?;
+    Some(base.join("aoc-inputs"))
+}
+
+/// Each site gets an index of its own, so inputs from a test server never stand in for real ones
+fn site_folder(base_url: &str) -> String {
+    let site = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest)
+        .trim_end_matches('/');
+    site.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn hex_hash(data: &str) -> String {
+    Sha256::digest(data.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, b| {
+            let _ = write!(hex, "{b:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = scratch("round-trip");
+        let cache = Cache::open(&dir, "https://adventofcode.com");
+        assert_eq!(None, cache.get(2015, 1));
+
+        cache.put(2015, 1, "(()))\n").unwrap();
+        cache.put(2016, 1, "(()))\n").unwrap();
+        assert_eq!(Some(String::from("(()))\n")), cache.get(2015, 1));
+        assert_eq!(Some(String::from("(()))\n")), cache.get(2016, 1));
+        // The same input is only stored the once
+        assert_eq!(1, fs::read_dir(dir.join("objects")).unwrap().count());
+
+        // Nor does another site see it
+        let other = Cache::open(&dir, "http://127.0.0.1:8080/");
+        assert_eq!(None, other.get(2015, 1));
+
+        // An input that's been changed is no longer trusted
+        let hash = hex_hash("(()))\n");
+        fs::write(dir.join("objects").join(hash), "tampered").unwrap();
+        assert_eq!(None, cache.get(2015, 1));
+    }
+
+    #[test]
+    fn test_default_dir() {
+        let dir = |xdg: Option<&str>, home: Option<&str>| {
+            default_dir(xdg.map(OsString::from), home.map(OsString::from))
+        };
+        assert_eq!(
+            Some(PathBuf::from("/xdg/aoc-inputs")),
+            dir(Some("/xdg"), Some("/home/me"))
+        );
+        assert_eq!(
+            Some(PathBuf::from("/home/me/.cache/aoc-inputs")),
+            dir(Some("relative"), Some("/home/me"))
+        );
+        assert_eq!(
+            Some(PathBuf::from("/home/me/.cache/aoc-inputs")),
+            dir(None, Some("/home/me"))
+        );
+        assert_eq!(None, dir(None, None));
+    }
+
+    #[test]
+    fn test_site_folder() {
+        assert_eq!("adventofcode.com", site_folder("https://adventofcode.com"));
+        assert_eq!("127.0.0.1_8080", site_folder("http://127.0.0.1:8080/"));
+    }
+}
@@ -1,15 +1,55 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+mod cache;
+mod throttle;
+mod unlock;
+
+use cache::Cache;
 use reqwest::blocking::Client;
-use reqwest::header;
-use std::env;
+use reqwest::{header, StatusCode};
 use std::error::Error;
-use std::io::Write;
-use std::path::PathBuf;
-use std::process::Command;
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, io};
+use throttle::Throttle;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Identifies the tool & where to find it to the site, as its automation guidelines ask
+const USER_AGENT: &str = concat!(
+    "cargo-aoc-inputs/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/jmacadie/aoc-rs)"
+);
+/// The shortest time allowed between two requests to the site
+const REQUEST_GAP: Duration = Duration::from_secs(2);
+const FIRST_YEAR: u16 = 2015;
+const LAST_YEAR: u16 = 2030;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let Ok(headers) = headers() else {
+    let options = match parse_args(env::args().skip(1), env::var("AOC_BASE_URL").ok()) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            usage();
+            return Ok(());
+        }
+        Err(e) => {
+            println!("{e}");
+            usage();
+            std::process::exit(2);
+        }
+    };
+
+    // A dry run never asks the site for anything, so doesn't need to be logged in
+    let headers = headers().or_else(|e| {
+        if options.run.dry_run {
+            Ok(header::HeaderMap::new())
+        } else {
+            Err(e)
+        }
+    });
+    let Ok(headers) = headers else {
         println!("Missing the environment variable: 'AOC_SESSION'");
         println!("Cannot download personal inputs without it. Please extract it from a current web session.");
         println!("The session can be set (in bash/zsh) with:");
@@ -18,85 +58,445 @@ fn main() -> Result<(), Box<dyn Error>> {
         return Ok(());
     };
 
-    match in_aoc_repo() {
+    let found = match &options.root {
+        Some(root) if root.is_dir() => Ok(root.clone()),
+        Some(root) => Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!("{} isn't a folder", root.display()),
+        )),
+        None => env::current_dir().and_then(|dir| root_dir(&dir)),
+    };
+    let root = match found {
+        Ok(root) => root,
         Err(e) => {
-            println!("Could not run git command, is it installed?");
             println!("{e}");
-            return Ok(());
+            println!("Run this from inside the advent of code repo, or point to it with --root");
+            std::process::exit(1);
         }
-        Ok(false) => {
-            println!(
-                "Don't appear to be in the advent of code repo. This command won't work outside."
-            );
-            return Ok(());
+    };
+
+    let client = client(headers)?;
+    let cache = cache::default_dir(env::var_os("XDG_CACHE_HOME"), env::var_os("HOME"))
+        .map(|dir| Cache::open(&dir, &options.base_url));
+    let site = Site {
+        client,
+        base_url: options.base_url,
+        cache,
+        throttle: Throttle::new(REQUEST_GAP),
+        now: SystemTime::now(),
+    };
+
+    // Loop through all the selected days
+    let years = options
+        .years
+        .unwrap_or_else(|| (FIRST_YEAR..=LAST_YEAR).collect());
+    let days = options.days.unwrap_or_else(|| (1..=25).collect());
+    for &year in &years {
+        for &day in &days {
+            match process_day(year, day, &root, &site, options.run) {
+                // Nothing more can be done for this day, but the others may still be fine
+                Err(e @ (DownloadError::Locked(_) | DownloadError::NotUnlocked)) => {
+                    println!("Skipping {year}, day {day}: {e}");
+                }
+                // Every other day would fail the same way, so there's no point carrying on
+                Err(e) => {
+                    println!("Stopping at {year}, day {day}: {e}");
+                    std::process::exit(1);
+                }
+                Ok(()) => (),
+            }
         }
-        Ok(true) => (), // continue
     }
+    Ok(())
+}
 
-    let root = root_dir()?;
+/// Settings from the command line, some of which can also come from the environment
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    /// Where to download the inputs from, without a trailing `/`
+    base_url: String,
+    /// The repo to fill in, rather than the one the tool is run from
+    root: Option<PathBuf>,
+    /// Only these years, or all of them if `None`
+    years: Option<Vec<u16>>,
+    /// Only these days of each year, or all of them if `None`
+    days: Option<Vec<u8>>,
+    run: Run,
+}
 
-    let client = reqwest::blocking::Client::builder()
-        .default_headers(headers)
-        .timeout(std::time::Duration::from_secs(10))
-        .build()?;
+/// How each day is handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Run {
+    /// Download inputs again even if they're already there, replacing them
+    force: bool,
+    /// Only say what would be downloaded
+    dry_run: bool,
+}
 
-    // Loop through all days
-    for year in 2015..=2030 {
-        for day in 1..=25 {
-            process_day(year, day, &root, &client)?;
+/// Read the arguments that follow the program name. Returns `None` if help was asked for
+///
+/// When run as `cargo aoc-inputs`, cargo passes the subcommand's name along first, so that's
+/// skipped over. A `--base-url` flag wins over the `AOC_BASE_URL` variable
+fn parse_args<I>(args: I, env_base_url: Option<String>) -> Result<Option<Options>, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    args.next_if(|a| a == "aoc-inputs");
+    let mut base_url = None;
+    let mut root = None;
+    let mut years = None;
+    let mut days = None;
+    let mut run = Run::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--base-url" => set_once(&mut base_url, next_value(&arg, &mut args)?, "base URLs")?,
+            "--root" => set_once(
+                &mut root,
+                PathBuf::from(next_value(&arg, &mut args)?),
+                "roots",
+            )?,
+            "--year" | "--years" => {
+                let spec = next_value(&arg, &mut args)?;
+                set_once(
+                    &mut years,
+                    parse_list(&spec, FIRST_YEAR, LAST_YEAR)?,
+                    "years",
+                )?;
+            }
+            "--day" | "--days" => {
+                let spec = next_value(&arg, &mut args)?;
+                let list = parse_list(&spec, 1, 25)?;
+                let list = list.into_iter().filter_map(|d| u8::try_from(d).ok());
+                set_once(&mut days, list.collect(), "days")?;
+            }
+            "--force" => run.force = true,
+            "--dry-run" => run.dry_run = true,
+            _ => return Err(format!("Unknown option: '{arg}'")),
         }
     }
+    let base_url = base_url
+        .or(env_base_url)
+        .unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+    Ok(Some(Options {
+        base_url: base_url.trim_end_matches('/').to_owned(),
+        root,
+        years,
+        days,
+        run,
+    }))
+}
+
+fn next_value<I>(arg: &str, args: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| format!("'{arg}' needs a value after it"))
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, name: &str) -> Result<(), String> {
+    if slot.is_some() {
+        return Err(format!("The {name} have been given more than once"));
+    }
+    *slot = Some(value);
     Ok(())
 }
 
-fn in_aoc_repo() -> Result<bool, Box<dyn Error>> {
-    const REMOTE_SSH: &str = "origin\tgit@github.com:jmacadie/aoc-rs.git (fetch)";
-    const REMOTE_HTTPS: &str = "origin\thttps://github.com/jmacadie/aoc-rs.git (fetch)";
-    let cmd = Command::new("git").args(["remote", "-v"]).output()?;
-    if !cmd.status.success() {
-        return Ok(false);
+/// Parse a comma separated list of numbers & inclusive ranges, e.g. `1,3,10-15`, into a sorted
+/// list without any repeats
+fn parse_list(spec: &str, min: u16, max: u16) -> Result<Vec<u16>, String> {
+    let number = |s: &str| match s.trim().parse() {
+        Ok(n) if (min..=max).contains(&n) => Ok(n),
+        Ok(n) => Err(format!("{n} is out of range, expected {min} to {max}")),
+        Err(_) => Err(format!("'{s}' is not a number")),
+    };
+    let mut out = Vec::new();
+    for part in spec.split(',').filter(|p| !p.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((a, b)) => (number(a)?, number(b)?),
+            None => (number(part)?, number(part)?),
+        };
+        if start > end {
+            return Err(format!("The range '{part}' is empty"));
+        }
+        out.extend(start..=end);
+    }
+    if out.is_empty() {
+        return Err(format!("Nothing selected by '{spec}'"));
+    }
+    out.sort_unstable();
+    out.dedup();
+    Ok(out)
+}
+
+fn usage() {
+    println!("Download any missing puzzle inputs for the days in this repo");
+    println!();
+    println!("Usage: cargo aoc-inputs [OPTIONS]");
+    println!();
+    println!("Options:");
+    println!("      --base-url <URL>  Site to download from, instead of {DEFAULT_BASE_URL}");
+    println!("                        Can also be set with AOC_BASE_URL");
+    println!(
+        "      --root <PATH>     The repo to download into, instead of the one this is run in"
+    );
+    println!("      --year <YEARS>    Only these years, e.g. 2016 or 2015,2017-2019");
+    println!("      --days <DAYS>     Only these days of each year, e.g. 5 or 10-15");
+    println!("      --force           Download inputs again even if they're already there,");
+    println!("                        showing what changed before replacing them");
+    println!("      --dry-run         List what would be downloaded, without doing it");
+    println!("  -h, --help            Show this message");
+}
+
+/// Where the inputs come from
+struct Site {
+    client: Client,
+    base_url: String,
+    /// `None` if there's nowhere to keep one, i.e. there's no home folder
+    cache: Option<Cache>,
+    throttle: Throttle,
+    /// When the run started, to tell which puzzles have unlocked
+    now: SystemTime,
+}
+
+fn client(headers: header::HeaderMap) -> reqwest::Result<Client> {
+    Client::builder()
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(10))
+        .build()
+}
+
+/// Why an input couldn't be downloaded
+#[derive(Debug)]
+enum DownloadError {
+    /// The session cookie is missing, wrong or has expired
+    Auth,
+    /// The puzzle won't unlock for this long yet, so it wasn't asked for
+    Locked(Duration),
+    /// The site says the puzzle isn't out yet, or doesn't exist
+    NotUnlocked,
+    /// The site wants us to slow down
+    RateLimited,
+    /// Any other status the site doesn't normally send, e.g. when it's down
+    Status(StatusCode),
+    /// The site couldn't be reached at all
+    Network(reqwest::Error),
+    /// The input came back fine, but couldn't be saved
+    Io(io::Error),
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auth => write!(f, "not logged in, check that AOC_SESSION is current"),
+            Self::Locked(wait) => write!(f, "the puzzle unlocks in {}", unlock::describe(*wait)),
+            Self::NotUnlocked => write!(f, "the puzzle hasn't unlocked yet"),
+            Self::RateLimited => write!(f, "too many requests, try again later"),
+            Self::Status(status) => write!(f, "the site replied with {status}"),
+            Self::Network(e) => write!(f, "couldn't reach the site: {e}"),
+            Self::Io(e) => write!(f, "couldn't save the input: {e}"),
+        }
     }
-    let out = String::from_utf8(cmd.stdout)?;
-    let first_remote = out.lines().next().unwrap_or("");
-    Ok(first_remote == REMOTE_SSH || first_remote == REMOTE_HTTPS)
 }
 
-fn root_dir() -> Result<String, Box<dyn Error>> {
-    let cmd = Command::new("git")
-        .args(["rev-parse", "--show-toplevel"])
-        .output()?;
-    if !cmd.status.success() {
-        return Ok(String::from("Not a git directory"));
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Network(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
     }
-    let mut result = cmd.stdout.as_slice();
-    while result.ends_with(b"\n") || result.ends_with(b"\r") {
-        (_, result) = result.split_last().unwrap();
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Network(e)
     }
-    Ok(String::from_utf8(result.to_vec())?)
 }
 
-fn process_day(year: u16, day: u8, root: &str, client: &Client) -> Result<(), Box<dyn Error>> {
-    let path = format!("{root}/{year}/day_{day:02}");
-    if !PathBuf::from(&path).exists() {
+impl From<io::Error> for DownloadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// The root of the advent of code repo, from `start` or any folder above it
+///
+/// Looks at the repo's structure rather than git, so it works the same in forks, copies &
+/// checkouts without a remote
+fn root_dir(start: &Path) -> io::Result<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| is_aoc_repo(dir))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "Couldn't find the advent of code repo in {} or any folder above it",
+                    start.display()
+                ),
+            )
+        })
+}
+
+/// Whether `dir` has a `Cargo.toml` depending on at least one day, whose crate is in its
+/// `yyyy/day_dd` folder
+fn is_aoc_repo(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml")).is_ok_and(|manifest| {
+        day_dependencies(&manifest)
+            .iter()
+            .any(|(year, day)| dir.join(format!("{year}/day_{day:02}/Cargo.toml")).exists())
+    })
+}
+
+/// The year & day of every `day_yyyy_dd` dependency in a manifest, skipping commented out ones
+fn day_dependencies(manifest: &str) -> Vec<(u16, u8)> {
+    manifest
+        .lines()
+        .filter_map(|line| {
+            let (name, _) = line.trim().split_once('=')?;
+            let (year, day) = name.trim().strip_prefix("day_")?.split_once('_')?;
+            if year.len() != 4 || day.len() != 2 {
+                return None;
+            }
+            Some((year.parse().ok()?, day.parse().ok()?))
+        })
+        .collect()
+}
+
+fn process_day(
+    year: u16,
+    day: u8,
+    root: &Path,
+    site: &Site,
+    run: Run,
+) -> Result<(), DownloadError> {
+    let path = root.join(format!("{year}/day_{day:02}"));
+    if !path.exists() {
         // Quit out if we don't have the day folder
         return Ok(());
     }
 
-    let input_file = PathBuf::from(format!("{path}/src/input.txt"));
-    if !input_file.exists() {
-        println!("Found missing data for {year}, day {day}. Downloading from website...");
-        let data = day_input(year, day, client)?;
-        let mut f = std::fs::File::create(input_file)?;
-        f.write_all(data.as_bytes())?;
+    let input_file = path.join("src/input.txt");
+    let existing = if input_file.exists() {
+        if !run.force {
+            return Ok(());
+        }
+        Some(String::from_utf8_lossy(&fs::read(&input_file)?).into_owned())
+    } else {
+        None
+    };
+
+    // A forced download is for an input that's suspect, which may well be what's in the cache
+    let cached = site
+        .cache
+        .as_ref()
+        .filter(|_| !run.force)
+        .and_then(|c| c.get(year, day));
+    if let Some(data) = cached {
+        if run.dry_run {
+            println!("Would restore {year}, day {day} from the cache");
+            return Ok(());
+        }
+        println!("Found missing data for {year}, day {day}. Restoring from the cache...");
+        save(&input_file, &data)?;
+        return Ok(());
+    }
+
+    if let Some(wait) = unlock::time_until(year, day, site.now) {
+        return Err(DownloadError::Locked(wait));
+    }
+    match (&existing, run.dry_run) {
+        (None, true) => println!("Would download {year}, day {day}"),
+        (Some(_), true) => println!("Would download {year}, day {day} again, replacing it"),
+        (None, false) => {
+            println!("Found missing data for {year}, day {day}. Downloading from website...");
+        }
+        (Some(_), false) => println!("Downloading {year}, day {day} again..."),
+    }
+    if run.dry_run {
+        return Ok(());
+    }
+
+    site.throttle.wait();
+    let input_url = format!("{}/{year}/day/{day}/input", site.base_url);
+    let data = day_input(&site.client, &input_url)?;
+    // Not being able to cache it shouldn't stop the input being used
+    if let Some(Err(e)) = site.cache.as_ref().map(|c| c.put(year, day, &data)) {
+        println!("Couldn't add {year}, day {day} to the cache: {e}");
     }
+    if let Some(old) = existing {
+        println!("  {}", diff_summary(&old, &data));
+    }
+    save(&input_file, &data)?;
 
     Ok(())
 }
 
-fn day_input(year: u16, day: u8, client: &Client) -> reqwest::Result<String> {
-    let input_url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    let res = client.get(input_url).send()?;
-    res.text()
+/// A one line idea of how much an input has changed, to show before it's replaced
+fn diff_summary(old: &str, new: &str) -> String {
+    if old == new {
+        return String::from("No changes");
+    }
+    let (old_lines, new_lines) = (old.lines().count(), new.lines().count());
+    let mut differ = old_lines.abs_diff(new_lines);
+    let mut first = None;
+    for (n, (a, b)) in old.lines().zip(new.lines()).enumerate() {
+        if a != b {
+            differ += 1;
+            first.get_or_insert(n + 1);
+        }
+    }
+    if differ == 0 {
+        return String::from("Only the line endings have changed");
+    }
+    // Any extra lines start straight after the shorter of the two
+    let first = first.unwrap_or_else(|| old_lines.min(new_lines) + 1);
+    format!(
+        "{differ} line(s) differ, starting at line {first}. Was {old_lines} lines & {} bytes, \
+         now {new_lines} lines & {} bytes",
+        old.len(),
+        new.len()
+    )
+}
+
+/// Write `data` to `file` all at once, by writing it alongside & then moving it into place, so a
+/// failed write can't leave half a file behind
+fn save(file: &Path, data: &str) -> io::Result<()> {
+    let mut partial = file.as_os_str().to_owned();
+    partial.push(".part");
+    fs::write(&partial, data).and_then(|()| fs::rename(&partial, file))
+}
+
+fn day_input(client: &Client, url: &str) -> Result<String, DownloadError> {
+    let res = client.get(url).send()?;
+    let status = res.status();
+    let body = res.text()?;
+    check_response(status, &body)?;
+    Ok(body)
+}
+
+/// Make sure the site actually sent an input, rather than an error page
+fn check_response(status: StatusCode, body: &str) -> Result<(), DownloadError> {
+    match status {
+        // The site has been known to send its login page back with a 200
+        StatusCode::OK if body.contains("Please log in") || body.starts_with("<!DOCTYPE") => {
+            Err(DownloadError::Auth)
+        }
+        StatusCode::OK => Ok(()),
+        // A missing or bad session gets a 400, saying that inputs differ by user
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            Err(DownloadError::Auth)
+        }
+        StatusCode::NOT_FOUND => Err(DownloadError::NotUnlocked),
+        StatusCode::TOO_MANY_REQUESTS => Err(DownloadError::RateLimited),
+        other => Err(DownloadError::Status(other)),
+    }
 }
 
 fn headers() -> Result<header::HeaderMap, Box<dyn Error>> {
@@ -110,22 +510,300 @@ fn headers() -> Result<header::HeaderMap, Box<dyn Error>> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// A stand-in for the site that answers a single request with `status` & `body`
+    fn serve(status: &str, body: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request);
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    /// An address that nothing is listening on, once the listener is dropped
+    fn unreachable() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    /// A fresh repo with a folder for 2015 day 1, & a cache of its own
+    fn scratch(name: &str) -> (PathBuf, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let root = dir.join("repo");
+        fs::create_dir_all(root.join("2015/day_01/src")).unwrap();
+        (root, dir.join("cache"))
+    }
+
+    fn site(base_url: String, cache: &Path) -> Site {
+        Site {
+            client: client(header::HeaderMap::new()).unwrap(),
+            cache: Some(Cache::open(cache, &base_url)),
+            base_url,
+            throttle: Throttle::new(Duration::ZERO),
+            now: SystemTime::now(),
+        }
+    }
+
+    #[test]
+    fn test_args() {
+        let args = |s: &str, env: Option<&str>| {
+            parse_args(
+                s.split_whitespace().map(String::from),
+                env.map(String::from),
+            )
+        };
+        let defaults = || Options {
+            base_url: String::from(DEFAULT_BASE_URL),
+            root: None,
+            years: None,
+            days: None,
+            run: Run::default(),
+        };
+        let url = |s: &str| {
+            Ok(Some(Options {
+                base_url: String::from(s),
+                ..defaults()
+            }))
+        };
+        assert_eq!(url(DEFAULT_BASE_URL), args("aoc-inputs", None));
+        assert_eq!(url("http://env"), args("", Some("http://env")));
+        assert_eq!(
+            url("http://flag"),
+            args("aoc-inputs --base-url http://flag/", Some("http://env"))
+        );
+        assert_eq!(
+            Ok(Some(Options {
+                root: Some(PathBuf::from("../aoc")),
+                ..defaults()
+            })),
+            args("--root ../aoc", None)
+        );
+        assert_eq!(
+            Ok(Some(Options {
+                years: Some(vec![2023]),
+                days: Some(vec![10, 11, 12, 13, 14, 15]),
+                run: Run {
+                    force: true,
+                    dry_run: true,
+                },
+                ..defaults()
+            })),
+            args("--year 2023 --days 10-15 --force --dry-run", None)
+        );
+        assert_eq!(
+            Ok(Some(Options {
+                years: Some(vec![2015, 2016, 2018]),
+                days: Some(vec![5]),
+                ..defaults()
+            })),
+            args("--years 2018,2015-2016 --day 5", None)
+        );
+        assert_eq!(Ok(None), args("--help", None));
+        assert!(args("--root", None).is_err());
+        assert!(args("--base-url", None).is_err());
+        assert!(args("--bogus", None).is_err());
+        assert!(args("--year 2014", None).is_err());
+        assert!(args("--days 15-10", None).is_err());
+        assert!(args("--day 26", None).is_err());
+        assert!(args("--day 1 --day 2", None).is_err());
+    }
+
+    #[test]
+    fn test_download() {
+        let (root, cache) = scratch("ok");
+        let file = root.join("2015/day_01/src/input.txt");
+        let ok = site(serve("200 OK", "1\n2\n3\n"), &cache);
+        assert!(process_day(2015, 1, &root, &ok, Run::default()).is_ok());
+        assert_eq!("1\n2\n3\n", fs::read_to_string(&file).unwrap());
+        assert!(!root.join("2015/day_01/src/input.txt.part").exists());
+
+        // Once it's cached the site isn't needed to get it back
+        fs::remove_file(&file).unwrap();
+        let offline = Site {
+            base_url: unreachable(),
+            ..ok
+        };
+        assert!(process_day(2015, 1, &root, &offline, Run::default()).is_ok());
+        assert_eq!("1\n2\n3\n", fs::read_to_string(&file).unwrap());
+    }
+
+    #[test]
+    fn test_force() {
+        let (root, cache) = scratch("force");
+        let file = root.join("2015/day_01/src/input.txt");
+        fs::write(&file, "1\n2\n").unwrap();
+
+        // Nothing is asked for unless forced to
+        let offline = site(unreachable(), &cache);
+        assert!(process_day(2015, 1, &root, &offline, Run::default()).is_ok());
+        assert_eq!("1\n2\n", fs::read_to_string(&file).unwrap());
+
+        let force = Run {
+            force: true,
+            dry_run: false,
+        };
+        let ok = site(serve("200 OK", "1\n3\n4\n"), &cache);
+        assert!(process_day(2015, 1, &root, &ok, force).is_ok());
+        assert_eq!("1\n3\n4\n", fs::read_to_string(&file).unwrap());
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let (root, cache) = scratch("dry-run");
+        let dry_run = Run {
+            force: true,
+            dry_run: true,
+        };
+        // Nothing is listening, so it'd be a network error if it were asked for
+        let offline = site(unreachable(), &cache);
+        assert!(process_day(2015, 1, &root, &offline, dry_run).is_ok());
+
+        offline.cache.as_ref().unwrap().put(2015, 1, "1\n").unwrap();
+        let dry_run = Run {
+            force: false,
+            dry_run: true,
+        };
+        assert!(process_day(2015, 1, &root, &offline, dry_run).is_ok());
+        assert_eq!(
+            0,
+            fs::read_dir(root.join("2015/day_01/src")).unwrap().count()
+        );
+    }
+
+    #[test]
+    fn test_diff_summary() {
+        assert_eq!("No changes", diff_summary("1\n2\n", "1\n2\n"));
+        assert_eq!(
+            "Only the line endings have changed",
+            diff_summary("1\r\n2\r\n", "1\n2\n")
+        );
+        assert_eq!(
+            "2 line(s) differ, starting at line 2. Was 2 lines & 4 bytes, now 3 lines & 6 bytes",
+            diff_summary("1\n2\n", "1\n3\n4\n")
+        );
+        assert_eq!(
+            "1 line(s) differ, starting at line 3. Was 2 lines & 4 bytes, now 3 lines & 6 bytes",
+            diff_summary("1\n2\n", "1\n2\n3\n")
+        );
+    }
+
+    #[test]
+    fn test_locked() {
+        let (root, cache) = scratch("locked");
+        // Nothing is listening, so it'd be a network error if it were asked for
+        let early = Site {
+            now: unlock::unlock_time(2015, 1) - Duration::from_secs(90),
+            ..site(unreachable(), &cache)
+        };
+        let err = process_day(2015, 1, &root, &early, Run::default()).unwrap_err();
+        assert!(matches!(err, DownloadError::Locked(wait) if wait.as_secs() == 90));
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let n = stream.read(&mut request).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .unwrap();
+            String::from_utf8_lossy(&request[..n]).to_lowercase()
+        });
+        let (root, cache) = scratch("user-agent");
+        process_day(2015, 1, &root, &site(base_url, &cache), Run::default()).unwrap();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("get /2015/day/1/input "));
+        assert!(request.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+    }
+
+    #[test]
+    fn test_download_errors() {
+        let (root, cache) = scratch("errors");
+        let cases = [
+            (
+                "400 Bad Request",
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            ("200 OK", "<!DOCTYPE html>\n<html>Please log in</html>"),
+            (
+                "404 Not Found",
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            ("429 Too Many Requests", ""),
+            ("500 Internal Server Error", "oops"),
+        ];
+        for (status, body) in cases {
+            let err = process_day(
+                2015,
+                1,
+                &root,
+                &site(serve(status, body), &cache),
+                Run::default(),
+            )
+            .unwrap_err();
+            match status {
+                "400 Bad Request" | "200 OK" => assert!(matches!(err, DownloadError::Auth)),
+                "404 Not Found" => assert!(matches!(err, DownloadError::NotUnlocked)),
+                "429 Too Many Requests" => assert!(matches!(err, DownloadError::RateLimited)),
+                _ => assert!(matches!(
+                    err,
+                    DownloadError::Status(StatusCode::INTERNAL_SERVER_ERROR)
+                )),
+            }
+        }
+
+        let err =
+            process_day(2015, 1, &root, &site(unreachable(), &cache), Run::default()).unwrap_err();
+        assert!(matches!(err, DownloadError::Network(_)));
+
+        // Nothing was saved, either in the repo or the cache
+        let src = fs::read_dir(root.join("2015/day_01/src")).unwrap();
+        assert_eq!(0, src.count());
+        assert!(!cache.exists());
+    }
 
     #[test]
-    fn test_in_aoc_repo() {
-        let out = in_aoc_repo();
-        assert!(out.is_ok());
-        let out = out.unwrap();
-        assert!(out);
+    fn test_day_dependencies() {
+        let manifest = "[dependencies]\n\
+                        aoc_solution = { path = \"solution\" }\n\
+                        day_2015_01 = { path = \"2015/day_01\" }\n\
+                        # day_2025_10 = { path = \"2025/day_10\" }\n\
+                        day_15_1 = \"1\"\n";
+        assert_eq!(vec![(2015, 1)], day_dependencies(manifest));
     }
 
     #[test]
     fn test_root_dir() {
-        let out = root_dir();
-        assert!(out.is_ok());
-        let out = out.unwrap();
+        // This crate lives in a subfolder of the repo
+        let here = env::current_dir().unwrap();
+        assert_eq!(here.parent().unwrap(), root_dir(&here).unwrap());
 
-        let dir = std::env::current_dir().unwrap();
-        assert_eq!(dir.parent(), Some(std::path::Path::new(&out)));
+        // Without any git at all
+        let (root, _) = scratch("root");
+        let nested = root.join("2015/day_01/src");
+        assert!(root_dir(&nested).is_err());
+        fs::write(
+            root.join("Cargo.toml"),
+            "day_2015_01 = { path = \"2015/day_01\" }",
+        )
+        .unwrap();
+        assert!(root_dir(&nested).is_err());
+        fs::write(root.join("2015/day_01/Cargo.toml"), "").unwrap();
+        assert_eq!(root, root_dir(&nested).unwrap());
     }
 }
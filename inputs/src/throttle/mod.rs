@@ -0,0 +1,47 @@
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Keeps requests to the site at least a set gap apart, so a repo with lots of missing inputs
+/// doesn't hammer it
+pub struct Throttle {
+    gap: Duration,
+    last: Cell<Option<Instant>>,
+}
+
+impl Throttle {
+    pub const fn new(gap: Duration) -> Self {
+        Self {
+            gap,
+            last: Cell::new(None),
+        }
+    }
+
+    /// Sleep until the gap since the last request is up, then count a new request as starting.
+    /// The first request never waits
+    pub fn wait(&self) {
+        if let Some(last) = self.last.get() {
+            if let Some(left) = self.gap.checked_sub(last.elapsed()) {
+                thread::sleep(left);
+            }
+        }
+        self.last.set(Some(Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wait() {
+        let gap = Duration::from_millis(50);
+        let throttle = Throttle::new(gap);
+        let start = Instant::now();
+        throttle.wait();
+        assert!(start.elapsed() < gap);
+        throttle.wait();
+        throttle.wait();
+        assert!(start.elapsed() >= gap * 2);
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight in the US Eastern time zone, which in December is always EST, UTC-5
+const UNLOCK_HOUR_UTC: u64 = 5;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// When a day's puzzle, & so its input, becomes available
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_since_epoch(year, 12, day);
+    UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY + UNLOCK_HOUR_UTC * 60 * 60)
+}
+
+/// How long until a day's puzzle unlocks, or `None` if it already has
+pub fn time_until(year: u16, day: u8, now: SystemTime) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(now)
+        .ok()
+        .filter(|d| !d.is_zero())
+}
+
+/// Days from 1970-01-01 to a date, from the proleptic Gregorian calendar, for dates after 1970
+///
+/// Counts years as starting in March, so the leap day falls at the end of the year
+fn days_since_epoch(year: u16, month: u8, day: u8) -> u64 {
+    let (year, month, day) = (u64::from(year), u64::from(month), u64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// A rough wait, e.g. `3 days` or `4h 05m`, as there's no need to be exact about it
+pub fn describe(wait: Duration) -> String {
+    let mins = wait.as_secs().div_ceil(60);
+    match mins / 60 {
+        hours if hours >= 48 => format!("{} days", hours / 24),
+        hours => format!("{hours}h {:02}m", mins % 60),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_days_since_epoch() {
+        assert_eq!(0, days_since_epoch(1970, 1, 1));
+        assert_eq!(59, days_since_epoch(1970, 3, 1));
+        assert_eq!(11_016, days_since_epoch(2000, 2, 29));
+        assert_eq!(16_770, days_since_epoch(2015, 12, 1));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2022-12-25T05:00:00Z
+        let christmas = unlock_time(2022, 25).duration_since(UNIX_EPOCH).unwrap();
+        assert_eq!(1_671_944_400, christmas.as_secs());
+
+        let unlock = unlock_time(2023, 1);
+        let second = Duration::from_secs(1);
+        assert_eq!(Some(second), time_until(2023, 1, unlock - second));
+        assert_eq!(None, time_until(2023, 1, unlock));
+        assert_eq!(None, time_until(2023, 1, unlock + second));
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!("0h 01m", describe(Duration::from_secs(1)));
+        assert_eq!("4h 05m", describe(Duration::from_mins(4 * 60 + 5)));
+        assert_eq!("47h 00m", describe(Duration::from_hours(47)));
+        assert_eq!(
+            "3 days",
+            describe(Duration::from_hours(3 * 24) + Duration::from_mins(1))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+
+use std::fmt::Display;
+
+mod screen;
+
+pub use screen::Screen;
+
+/// A single day's puzzle, split into its steps so that each one can be called on its own
+///
+/// The runner uses this to solve a day on any input & pick up the answers without having to
+/// scrape them out of what the day prints
+///
+/// The input is always `'static` so that the parsed data can keep hold of slices of it, in the
+/// same way the days always have with their `include_str!` input
+pub trait Solution {
+    /// Whatever the input is turned into before either part is solved. Days that do all their
+    /// work in the parts themselves can just pass the raw input through
+    type Parsed;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(data: &'static str) -> Self::Parsed;
+    /// Either part can change the parsed input as it goes, as some days work through it in place
+    fn part_one(parsed: &mut Self::Parsed) -> Self::PartOne;
+    /// Given the parsed input once part 1 has been through it when solving both, so a day whose
+    /// second part carries on from the first doesn't have to start over. Such a day needs to
+    /// override `example` too, as the examples are solved one part at a time
+    fn part_two(parsed: &mut Self::Parsed) -> Self::PartTwo;
+
+    /// Solve one part of one of the day's examples
+    ///
+    /// Most days solve an example just as they would their input, but days sized to fit their
+    /// input, or whose examples ask for different settings, override this to solve them the way
+    /// their tests do
+    #[must_use]
+    fn example(data: &'static str, part: u8) -> String
+    where
+        Self: Sized,
+    {
+        solve_part::<Self>(data, part)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: String,
+    pub part_two: String,
+}
+
+impl Answers {
+    /// The answer to part 1 or 2
+    #[must_use]
+    pub fn part(&self, part: u8) -> &str {
+        match part {
+            1 => &self.part_one,
+            _ => &self.part_two,
+        }
+    }
+}
+
+/// Solve both parts of a day, returning the answers
+#[must_use]
+pub fn solve<S: Solution>(data: &'static str) -> Answers {
+    let mut parsed = S::parse(data);
+    Answers {
+        part_one: S::part_one(&mut parsed).to_string(),
+        part_two: S::part_two(&mut parsed).to_string(),
+    }
+}
+
+/// Solve just one part of a day, for inputs like the examples that only apply to one of them
+///
+/// Part 2 is given the input straight from parsing, without part 1 having been through it
+#[must_use]
+pub fn solve_part<S: Solution>(data: &'static str, part: u8) -> String {
+    let mut parsed = S::parse(data);
+    match part {
+        1 => S::part_one(&mut parsed).to_string(),
+        _ => S::part_two(&mut parsed).to_string(),
+    }
+}
+
+/// Solve both parts of a day, printing out the answers
+pub fn print<S: Solution>(data: &'static str) {
+    let mut parsed = S::parse(data);
+    print_part(1, S::part_one(&mut parsed));
+    print_part(2, S::part_two(&mut parsed));
+}
+
+/// Answers that take up more than one line, like a screen that couldn't be read, start on a line
+/// of their own so they line up
+fn print_part(part: u8, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = String;
+
+        fn parse(data: &'static str) -> Self::Parsed {
+            data.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        // Leaves the total on the end for part 2
+        fn part_one(parsed: &mut Self::Parsed) -> Self::PartOne {
+            let total = parsed.iter().sum();
+            parsed.push(total);
+            total
+        }
+
+        fn part_two(parsed: &mut Self::Parsed) -> Self::PartTwo {
+            let total = parsed.pop().unwrap();
+            format!("{} numbers, {total} in all", parsed.len())
+        }
+    }
+
+    #[test]
+    fn solve_both_parts() {
+        let answers = solve::<Sum>("1\n2\n3\n");
+        assert_eq!("6", answers.part_one);
+        assert_eq!("3 numbers, 6 in all", answers.part_two);
+    }
+
+    #[test]
+    fn solve_one_part() {
+        assert_eq!("6", solve_part::<Sum>("1\n2\n3\n", 1));
+        // Without part 1's total to go on, part 2 takes the last number for it
+        assert_eq!("2 numbers, 3 in all", solve_part::<Sum>("1\n2\n3\n", 2));
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+/// The height of the block letters that the puzzles draw on their screens
+const LETTER_HEIGHT: usize = 6;
+
+/// Every letter that's turned up in a puzzle so far, in the 6 pixel high font they're all drawn in
+const LETTERS: [(char, [&str; LETTER_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// A grid of lit & unlit pixels, for the days whose answer is drawn rather than worked out
+///
+/// Shows as the text written on it if it can be read, otherwise as the grid itself, with `#` for
+/// the lit pixels & `.` for the rest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screen {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Screen {
+    /// A screen with every pixel switched off
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    /// A screen made from rows of pixels, which are all expected to be the same length
+    pub fn from_rows<R, P>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = P>,
+        P: IntoIterator<Item = bool>,
+    {
+        let mut width = 0;
+        let mut pixels = Vec::new();
+        for row in rows {
+            let start = pixels.len();
+            pixels.extend(row);
+            width = pixels.len() - start;
+        }
+        Self { width, pixels }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.pixels.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, on: bool) {
+        self.pixels[y * self.width + x] = on;
+    }
+
+    #[must_use]
+    pub fn count_on(&self) -> usize {
+        self.pixels.iter().filter(|&&on| on).count()
+    }
+
+    /// Read the letters written across the screen, if it's a row of block letters
+    ///
+    /// Letters are split up at the blank columns between them, so it doesn't matter how they're
+    /// spaced out. Returns `None` if any of the letters aren't in the font
+    #[must_use]
+    pub fn read(&self) -> Option<String> {
+        if self.height() != LETTER_HEIGHT {
+            return None;
+        }
+        let column = |x| {
+            (0..LETTER_HEIGHT)
+                .map(|y| self.get(x, y))
+                .collect::<Vec<_>>()
+        };
+        let mut text = String::new();
+        let mut letter: Vec<Vec<bool>> = Vec::new();
+        for x in 0..=self.width {
+            let col = (x < self.width).then(|| column(x));
+            match col {
+                Some(col) if col.contains(&true) => letter.push(col),
+                _ if letter.is_empty() => {}
+                _ => {
+                    text.push(recognise(&letter)?);
+                    letter.clear();
+                }
+            }
+        }
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// The pixels drawn out a row at a time
+    #[must_use]
+    pub fn render(&self) -> String {
+        self.pixels
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// The letter with exactly these columns of pixels
+fn recognise(columns: &[Vec<bool>]) -> Option<char> {
+    LETTERS.iter().find_map(|(c, rows)| {
+        let matches = rows[0].len() == columns.len()
+            && rows.iter().enumerate().all(|(y, row)| {
+                row.bytes()
+                    .zip(columns)
+                    .all(|(pixel, col)| (pixel == b'#') == col[y])
+            });
+        matches.then_some(*c)
+    })
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.read() {
+            Some(text) => write!(f, "{text}"),
+            None => write!(f, "{}", self.render()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &str) -> Screen {
+        Screen::from_rows(rows.lines().map(|row| row.bytes().map(|b| b == b'#')))
+    }
+
+    #[test]
+    fn reads_letters() {
+        // Spaced out like the 5 pixel wide letters of 2016, with the 5 wide Y
+        let hi = screen(
+            "#..#..###..#...#\n\
+             #..#...#...#...#\n\
+             ####...#....#.#.\n\
+             #..#...#.....#..\n\
+             #..#...#.....#..\n\
+             #..#..###....#..",
+        );
+        assert_eq!(Some(String::from("HIY")), hi.read());
+        assert_eq!("HIY", hi.to_string());
+    }
+
+    #[test]
+    fn shows_unreadable_grid() {
+        let grid = screen(".#..#.#\n#.#....\n.#.....");
+        assert_eq!(None, grid.read());
+        assert_eq!(".#..#.#\n#.#....\n.#.....", grid.to_string());
+        assert_eq!(6, grid.count_on());
+        assert_eq!((7, 3), (grid.width(), grid.height()));
+
+        let mut blank = Screen::new(4, LETTER_HEIGHT);
+        assert_eq!(None, blank.read());
+        blank.set(1, 2, true);
+        assert!(blank.get(1, 2));
+        assert_eq!(None, blank.read());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::days::Year;
+use aoc_solution::Answers;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+/// Local (git ignored) file of the known correct answers for my inputs
+const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail,
+    /// No answer on record to check against
+    Missing,
+    /// No answer on record, but the latest one has now been saved
+    Recorded,
+}
+
+impl Check {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Missing => "---",
+            Self::Recorded => "NEW",
+        }
+    }
+}
+
+/// The known answers, keyed on year, day & part
+#[derive(Debug, Default)]
+pub struct Store(BTreeMap<(u16, usize, u8), String>);
+
+impl Store {
+    pub fn load() -> io::Result<Self> {
+        let file = match File::open(ANSWERS_FILE) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        let mut store = Self::default();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let mut parts = line.splitn(4, '\t');
+            let (Some(year), Some(day), Some(part), Some(answer)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+            let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+                continue;
+            };
+            store.0.insert((year, day, part), unescape(answer));
+        }
+        Ok(store)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(ANSWERS_FILE)?);
+        for ((year, day, part), answer) in &self.0 {
+            writeln!(file, "{year}\t{day}\t{part}\t{}", escape(answer))?;
+        }
+        file.flush()
+    }
+
+    /// Compare one part of a day's answers against those on record, saving it as the known
+    /// answer if there wasn't one already & `record` is set
+    pub fn check(
+        &mut self,
+        year: Year,
+        day: usize,
+        part: u8,
+        actual: &Answers,
+        record: bool,
+    ) -> Check {
+        let key = (year.number(), day, part);
+        let actual = actual.part(part);
+        match self.0.get(&key) {
+            Some(expected) if expected == actual => Check::Pass,
+            Some(_) => Check::Fail,
+            None if record => {
+                self.0.insert(key, actual.to_owned());
+                Check::Recorded
+            }
+            None => Check::Missing,
+        }
+    }
+
+    pub fn expected(&self, year: Year, day: usize, part: u8) -> Option<&String> {
+        self.0.get(&(year.number(), day, part))
+    }
+}
+
+/// Keep each answer to one line of the file, as screens that can't be read span several
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Undo `escape`, which the example answers use too
+pub fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let mut store = Store::default();
+        let actual = Answers {
+            part_one: String::from("10"),
+            part_two: String::from("20"),
+        };
+        assert_eq!(
+            Check::Missing,
+            store.check(Year::Y2015, 1, 1, &actual, false)
+        );
+        assert_eq!(
+            Check::Recorded,
+            store.check(Year::Y2015, 1, 1, &actual, true)
+        );
+        assert_eq!(Check::Pass, store.check(Year::Y2015, 1, 1, &actual, false));
+        assert_eq!(
+            Check::Missing,
+            store.check(Year::Y2015, 1, 2, &actual, false)
+        );
+
+        let wrong = Answers {
+            part_one: String::from("11"),
+            part_two: String::from("20"),
+        };
+        assert_eq!(Check::Fail, store.check(Year::Y2015, 1, 1, &wrong, true));
+        assert_eq!(Some(&String::from("10")), store.expected(Year::Y2015, 1, 1));
+    }
+
+    #[test]
+    fn multi_line() {
+        let screen = "#..#\n.##.\\";
+        assert_eq!("#..#\\n.##.\\\\", escape(screen));
+        assert_eq!(screen, unescape(&escape(screen)));
+        assert_eq!("EFEYKFRFIJ", unescape("EFEYKFRFIJ"));
+    }
+}
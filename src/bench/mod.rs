@@ -1,24 +1,752 @@
-pub fn bench(f: fn()) -> std::time::Duration {
-    let estimate = bench_inner(f);
-    if estimate.as_secs() > 0 {
-        return estimate;
+use crate::days::Day;
+use aoc_solution::{Answers, Solution};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Days run on their own thread, which gets the same size stack as the main thread would, as
+/// some of them lean on it quite heavily
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+/// How often `watch` checks in while a day is running
+const TICK: Duration = Duration::from_millis(100);
+
+/// Threads started by `watch` that haven't finished yet, which includes any that were abandoned
+/// when their day timed out
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+/// How long each step of a single run of a day took
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn total(self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Solve both parts of a day, timing each step separately
+pub fn time<S: Solution>(data: &'static str) -> Timings {
+    let timer = Instant::now();
+    let mut parsed = S::parse(black_box(data));
+    let parse = timer.elapsed();
+
+    let timer = Instant::now();
+    black_box(S::part_one(&mut parsed));
+    let part_one = timer.elapsed();
+
+    let timer = Instant::now();
+    black_box(S::part_two(&mut parsed));
+    let part_two = timer.elapsed();
+
+    Timings {
+        parse,
+        part_one,
+        part_two,
+    }
+}
+
+/// Summary statistics for the repeated runs of a single day
+///
+/// Outliers are dropped before any of the stats are calculated, see `reject_outliers`
+#[derive(Debug, Clone, Copy)]
+pub struct BenchResult {
+    pub samples: usize,
+    pub outliers: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Median time of each step, taken across all the runs
+    pub phases: Timings,
+    /// Timed while a day that ran out of time earlier was still going in the background, so the
+    /// times are likely to be too slow
+    pub contended: bool,
+}
+
+impl BenchResult {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample to benchmark");
+        samples.sort_unstable();
+        let total = samples.len();
+        let kept = reject_outliers(&samples);
+
+        let n = kept.len();
+        let nanos = kept.iter().map(Duration::as_nanos).collect::<Vec<_>>();
+        let sum = nanos.iter().sum::<u128>();
+        #[allow(clippy::cast_precision_loss)]
+        let mean = sum as f64 / n as f64;
+        #[allow(clippy::cast_precision_loss)]
+        let variance = if n > 1 {
+            nanos
+                .iter()
+                .map(|&x| (x as f64 - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            samples: n,
+            outliers: total - n,
+            min: kept[0],
+            max: kept[n - 1],
+            mean: from_nanos_f64(mean),
+            median: median(kept),
+            p95: percentile(kept, 95),
+            std_dev: from_nanos_f64(variance.sqrt()),
+            phases: Timings::default(),
+            contended: false,
+        }
+    }
+
+    /// The stats of the total time of each run, along with the median time of each step
+    pub fn from_timings(runs: &[Timings]) -> Self {
+        let mut res = Self::from_samples(runs.iter().map(|t| t.total()).collect());
+        let phase = |f: fn(&Timings) -> Duration| {
+            let mut samples = runs.iter().map(f).collect::<Vec<_>>();
+            samples.sort_unstable();
+            median(&samples)
+        };
+        res.phases = Timings {
+            parse: phase(|t| t.parse),
+            part_one: phase(|t| t.part_one),
+            part_two: phase(|t| t.part_two),
+        };
+        res
+    }
+}
+
+/// Why a day didn't give a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The day panicked, with the panic message if it had one
+    Panic(String),
+    /// The day was still going when the time ran out
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// Short name to show in place of a day's timings
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Panic(_) => "PANIC",
+            Self::Timeout(_) => "TIMEOUT",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panic(msg) => write!(f, "panicked: {msg}"),
+            Self::Timeout(after) => write!(f, "timed out after {after:?}"),
+        }
+    }
+}
+
+/// How hard to work at getting steady timings for each day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs first, to get the caches & branch predictors warmed up
+    pub warmup: usize,
+    /// Roughly how long to spend on the timed runs
+    pub target: Duration,
+    pub min_runs: usize,
+    pub max_runs: usize,
+}
+
+impl BenchConfig {
+    pub const DEFAULT: Self = Self {
+        warmup: 3,
+        target: Duration::from_secs(2),
+        min_runs: 1,
+        max_runs: 1000,
+    };
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Benchmark a day on its own thread, calling `progress` every so often with the number of runs
+/// done so far & how long it's been going
+pub fn bench(
+    day: Day,
+    config: BenchConfig,
+    timeout: Duration,
+    mut progress: impl FnMut(usize, Duration),
+) -> Result<BenchResult, Failure> {
+    // Each bench gets its own count, so a day that timed out & is still going can't add to it
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&runs);
+    // Such a day is also fighting this one for the CPU, so the times can't be trusted
+    let contended = strays() > 0;
+    watch(
+        move || bench_runs(day, config, &counter),
+        timeout,
+        |elapsed| {
+            progress(runs.load(Ordering::Relaxed), elapsed);
+        },
+    )
+    .map(|res| BenchResult { contended, ..res })
+}
+
+/// Roughly how long `bench` takes for a day, given how long one run of it takes
+pub fn expected_time(config: BenchConfig, per_run: Duration) -> Duration {
+    if per_run >= config.target && config.min_runs <= 1 {
+        return per_run;
+    }
+    // Warming up stops early once the target is used up, but there's always at least one run
+    let fit = config.target.as_nanos().div_ceil(per_run.as_nanos().max(1));
+    let warmup = config
+        .warmup
+        .min(usize::try_from(fit).unwrap_or(usize::MAX))
+        .max(1);
+    let total = warmup + runs(config, per_run);
+    per_run * u32::try_from(total).unwrap_or(u32::MAX)
+}
+
+/// Keep this thread, & any it starts from now on, on the one CPU
+///
+/// Stops the OS moving the days between CPUs mid run, which muddies the timings of the quickest
+/// days. Only possible on Linux for now
+#[cfg(target_os = "linux")]
+pub fn pin_to_cpu(cpu: usize) -> io::Result<()> {
+    extern "C" {
+        fn sched_setaffinity(pid: i32, size: usize, mask: *const u64) -> i32;
+    }
+
+    // The same layout as glibc's `cpu_set_t`, one bit for each of 1024 CPUs
+    let mut mask = [0_u64; 16];
+    if cpu >= mask.len() * 64 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("CPU {cpu} is out of range"),
+        ));
+    }
+    mask[cpu / 64] |= 1 << (cpu % 64);
+    // SAFETY: the mask is the size it's said to be, & pid 0 is the calling thread
+    let res = unsafe { sched_setaffinity(0, std::mem::size_of_val(&mask), mask.as_ptr()) };
+    if res == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_to_cpu(_cpu: usize) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "pinning to a CPU is only supported on Linux",
+    ))
+}
+
+/// Run `f` on its own thread, catching any panic & abandoning it once `timeout` has passed
+///
+/// There's no way to stop a thread from the outside, so one that times out is left running in
+/// the background until the runner exits
+pub fn isolate<T, F>(f: F, timeout: Duration) -> Result<T, Failure>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    watch(f, timeout, |_| {})
+}
+
+/// How many days that timed out are still running in the background
+///
+/// Only makes sense when nothing is being isolated, as a day that's still being watched counts too
+pub fn strays() -> usize {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+/// `isolate`, but calling `tick` with the time so far every so often while `f` is still going
+pub fn watch<T, F>(f: F, timeout: Duration, mut tick: impl FnMut(Duration)) -> Result<T, Failure>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    // The panic gets reported alongside the day, so the default hook printing it over the top of
+    // everything isn't wanted
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    // Some days still print bits of debug output as they go
+    let gag = gag::Gag::stdout().unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    RUNNING.fetch_add(1, Ordering::SeqCst);
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let res = panic::catch_unwind(AssertUnwindSafe(f));
+            // Before sending, so the count is already down by the time the result is received
+            RUNNING.fetch_sub(1, Ordering::SeqCst);
+            // Nobody is listening any more if the day timed out
+            let _ = tx.send(res);
+        })
+        .expect("can start a thread to run the day on");
+    let started = Instant::now();
+    let res = loop {
+        let left = timeout.saturating_sub(started.elapsed());
+        match rx.recv_timeout(left.min(TICK)) {
+            Err(RecvTimeoutError::Timeout) if left > TICK => tick(started.elapsed()),
+            res => break res,
+        }
+    };
+
+    drop(gag);
+    panic::set_hook(hook);
+    match res {
+        Ok(Ok(out)) => Ok(out),
+        Ok(Err(payload)) => Err(Failure::Panic(panic_message(payload.as_ref()))),
+        Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout(timeout)),
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(Failure::Panic(String::from("the day's thread died")))
+        }
+    }
+}
+
+/// Word from the threads in `solve_all` on how each day is getting on
+enum Progress {
+    /// Along with the flag that tells the thread to stop once it's done with the day
+    Started(usize, Instant, Arc<AtomicBool>),
+    Done(usize, Result<(Answers, Duration), Failure>),
+}
+
+/// Solve each day once, spread over `jobs` threads, & time how long each one took
+///
+/// The days are all fighting each other for the CPU, so the times are only a rough guide. A day
+/// that runs out of time leaves its thread stuck, so another one is started to take its place,
+/// & the stuck one stops if it ever gets to the end of the day so no more than `jobs` are busy
+pub fn solve_all(
+    days: &[Day],
+    jobs: usize,
+    timeout: Duration,
+) -> Vec<Result<(Answers, Duration), Failure>> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let gag = gag::Gag::stdout().unwrap();
+
+    // Each thread takes the next day off the list as soon as it's free, as some days take much
+    // longer than others
+    let list: Arc<[Day]> = days.into();
+    let next = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::channel();
+    let start_worker = || {
+        let (list, next, tx) = (Arc::clone(&list), Arc::clone(&next), tx.clone());
+        let retired = Arc::new(AtomicBool::new(false));
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(move || loop {
+                if retired.load(Ordering::SeqCst) {
+                    break;
+                }
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = list.get(i) else {
+                    break;
+                };
+                let started = Instant::now();
+                let _ = tx.send(Progress::Started(i, started, Arc::clone(&retired)));
+                let res = panic::catch_unwind(|| day.run())
+                    .map(|answers| (answers, started.elapsed()))
+                    .map_err(|payload| Failure::Panic(panic_message(payload.as_ref())));
+                let _ = tx.send(Progress::Done(i, res));
+            })
+            .expect("can start a thread to run the days on");
+    };
+    for _ in 0..jobs.min(days.len()) {
+        start_worker();
+    }
+
+    let mut out = days.iter().map(|_| None).collect::<Vec<_>>();
+    let mut running = HashMap::new();
+    let mut left = days.len();
+    while left > 0 {
+        // Wake up in time to catch the next day that's due to run out of time
+        let wait = running
+            .values()
+            .map(|&(started, _): &(Instant, _)| {
+                (started + timeout).saturating_duration_since(Instant::now())
+            })
+            .min()
+            .unwrap_or(timeout);
+        match rx.recv_timeout(wait) {
+            Ok(Progress::Started(i, started, retired)) => {
+                running.insert(i, (started, retired));
+            }
+            // A day that's already been given up on could still finish late
+            Ok(Progress::Done(i, res)) => {
+                if running.remove(&i).is_some() {
+                    out[i] = Some(res);
+                    left -= 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let expired = running
+                    .iter()
+                    .filter(|(_, (started, _))| started.elapsed() >= timeout)
+                    .map(|(&i, _)| i)
+                    .collect::<Vec<_>>();
+                for i in expired {
+                    if let Some((_, retired)) = running.remove(&i) {
+                        retired.store(true, Ordering::SeqCst);
+                    }
+                    out[i] = Some(Err(Failure::Timeout(timeout)));
+                    left -= 1;
+                    start_worker();
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept for new threads"),
+        }
+    }
+
+    drop(gag);
+    panic::set_hook(hook);
+    out.into_iter()
+        .map(|res| res.expect("every day is done or timed out"))
+        .collect()
+}
+
+/// Panics are almost always raised with a string, from either a literal or a `format!`
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("no message"))
+}
+
+fn bench_runs(day: Day, config: BenchConfig, runs_done: &AtomicUsize) -> BenchResult {
+    // A day that uses up the target in one go would only get the one timed run, so its first run
+    // is kept as that rather than spending as long again on another
+    let first = bench_inner(day, runs_done);
+    if first.total() >= config.target && config.min_runs <= 1 {
+        return BenchResult::from_timings(&[first]);
+    }
+
+    // The last warmup run gives the estimate for how long each run takes. Slow days stop warming
+    // up early, once they've used up the target time. The first run counts as warming up, even
+    // if there isn't meant to be any, as it's still needed for the estimate
+    let mut estimate = first.total();
+    let mut warming = estimate;
+    for _ in 1..config.warmup {
+        if warming >= config.target {
+            break;
+        }
+        estimate = bench_inner(day, runs_done).total();
+        warming += estimate;
+    }
+
+    let runs = runs(config, estimate);
+    let timings = (0..runs)
+        .map(|_| bench_inner(day, runs_done))
+        .collect::<Vec<_>>();
+    BenchResult::from_timings(&timings)
+}
+
+/// How many runs fit in the target time, kept within the min & max
+fn runs(config: BenchConfig, estimate: Duration) -> usize {
+    let fit = config.target.as_nanos() / estimate.as_nanos().max(1);
+    usize::try_from(fit)
+        .unwrap_or(usize::MAX)
+        .clamp(config.min_runs, config.max_runs)
+}
+
+fn bench_inner(day: Day, runs_done: &AtomicUsize) -> Timings {
+    let timings = day.time();
+    runs_done.fetch_add(1, Ordering::Relaxed);
+    timings
+}
+
+/// Drop any samples that sit outside the Tukey fences, i.e. more than 1.5 times the
+/// inter-quartile range below the lower quartile or above the upper quartile
+///
+/// Expects the samples to be sorted & returns the (still sorted) slice of samples that are kept
+fn reject_outliers(sorted: &[Duration]) -> &[Duration] {
+    // Not enough data to say anything meaningful about the spread
+    if sorted.len() < 4 {
+        return sorted;
     }
+    let q1 = percentile(sorted, 25);
+    let q3 = percentile(sorted, 75);
+    let fence = q3.saturating_sub(q1) * 3 / 2;
+    let low = q1.saturating_sub(fence);
+    let high = q3 + fence;
 
-    // How many runs can we fit in 2 seconds
-    let mut runs = std::time::Duration::from_secs(2).as_nanos() / estimate.as_nanos();
-    runs = std::cmp::min(runs, 100); // ... but don't do more than 100, that would be silly
+    let start = sorted.partition_point(|&d| d < low);
+    let end = sorted.partition_point(|&d| d <= high);
+    &sorted[start..end]
+}
 
-    // TODO: Ideally would return an average time and runtime range (after throwing out outliers)
-    // as opposed to the single min time output currently
-    //let out = (0..runs).map(|_| bench_inner(f)).collect::<Vec<_>>();
+fn median(sorted: &[Duration]) -> Duration {
+    let n = sorted.len();
+    if n.is_multiple_of(2) {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+    } else {
+        sorted[n / 2]
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty, list of samples
+fn percentile(sorted: &[Duration], pcnt: usize) -> Duration {
+    let rank = (pcnt * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
 
-    (0..runs).map(|_| bench_inner(f)).min().unwrap()
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn from_nanos_f64(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
 }
 
-fn bench_inner(f: fn()) -> std::time::Duration {
-    let _gag = gag::Gag::stdout().unwrap();
-    let timer = std::time::Instant::now();
-    f();
-    timer.elapsed()
-    // gag dropped here
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(v: &[u64]) -> Vec<Duration> {
+        v.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let res = BenchResult::from_samples(micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(5, res.samples);
+        assert_eq!(0, res.outliers);
+        assert_eq!(Duration::from_micros(1), res.min);
+        assert_eq!(Duration::from_micros(5), res.max);
+        assert_eq!(Duration::from_micros(3), res.mean);
+        assert_eq!(Duration::from_micros(3), res.median);
+        assert_eq!(Duration::from_micros(5), res.p95);
+        // sqrt(2.5) = 1.5811...
+        assert_eq!(Duration::from_nanos(1_581), res.std_dev);
+    }
+
+    #[test]
+    fn single_sample() {
+        let res = BenchResult::from_samples(micros(&[7]));
+        assert_eq!(1, res.samples);
+        assert_eq!(Duration::from_micros(7), res.median);
+        assert_eq!(Duration::ZERO, res.std_dev);
+    }
+
+    #[test]
+    fn outliers() {
+        let res = BenchResult::from_samples(micros(&[10, 11, 10, 12, 11, 10, 250, 11, 1]));
+        assert_eq!(7, res.samples);
+        assert_eq!(2, res.outliers);
+        assert_eq!(Duration::from_micros(10), res.min);
+        assert_eq!(Duration::from_micros(12), res.max);
+    }
+
+    #[test]
+    fn phases() {
+        let run = |parse, part_one, part_two| Timings {
+            parse: Duration::from_micros(parse),
+            part_one: Duration::from_micros(part_one),
+            part_two: Duration::from_micros(part_two),
+        };
+        let res = BenchResult::from_timings(&[run(1, 10, 100), run(3, 30, 300), run(2, 20, 200)]);
+        assert_eq!(3, res.samples);
+        assert_eq!(Duration::from_micros(222), res.median);
+        assert_eq!(run(2, 20, 200), res.phases);
+    }
+
+    fn solved(_: &'static str) -> Answers {
+        Answers {
+            part_one: String::from("1"),
+            part_two: String::from("2"),
+        }
+    }
+
+    fn broken(_: &'static str) -> Answers {
+        panic!("broken")
+    }
+
+    #[test]
+    fn run_counts() {
+        let config = BenchConfig::DEFAULT;
+        assert_eq!(1000, runs(config, Duration::from_nanos(500)));
+        assert_eq!(200, runs(config, Duration::from_millis(10)));
+        assert_eq!(1, runs(config, Duration::from_secs(5)));
+        assert_eq!(1000, runs(config, Duration::ZERO));
+
+        let config = BenchConfig {
+            min_runs: 5,
+            max_runs: 50,
+            ..config
+        };
+        assert_eq!(5, runs(config, Duration::from_secs(5)));
+        assert_eq!(50, runs(config, Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn expected_times() {
+        let config = BenchConfig::DEFAULT;
+        // 3 warmup runs, then 200 timed ones
+        assert_eq!(
+            Duration::from_millis(2030),
+            expected_time(config, Duration::from_millis(10))
+        );
+        // The first run uses up the target, so it's the only one
+        assert_eq!(
+            Duration::from_secs(5),
+            expected_time(config, Duration::from_secs(5))
+        );
+        // Two warmup runs use up the target, then there's the one timed run
+        assert_eq!(
+            Duration::from_millis(3600),
+            expected_time(config, Duration::from_millis(1200))
+        );
+
+        let config = BenchConfig {
+            warmup: 0,
+            ..config
+        };
+        assert_eq!(
+            Duration::from_secs(5),
+            expected_time(config, Duration::from_secs(5))
+        );
+        let config = BenchConfig {
+            min_runs: 2,
+            ..config
+        };
+        assert_eq!(
+            Duration::from_secs(15),
+            expected_time(config, Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn slow_days() {
+        let day = |time| Day {
+            number: 1,
+            name: "Day",
+            title: "",
+            input: "",
+            solve: solved,
+            example: |_, _| String::new(),
+            time,
+        };
+        let slow = day(|_| Timings {
+            part_one: Duration::from_secs(3),
+            ..Timings::default()
+        });
+        let runs = AtomicUsize::new(0);
+        let res = bench_runs(slow, BenchConfig::DEFAULT, &runs);
+        assert_eq!((1, 1), (runs.load(Ordering::Relaxed), res.samples));
+
+        // Two warmup runs, then the one timed run
+        let slowish = day(|_| Timings {
+            part_one: Duration::from_millis(1200),
+            ..Timings::default()
+        });
+        let runs = AtomicUsize::new(0);
+        let res = bench_runs(slowish, BenchConfig::DEFAULT, &runs);
+        assert_eq!((3, 1), (runs.load(Ordering::Relaxed), res.samples));
+
+        let quick = day(|_| Timings {
+            part_one: Duration::from_millis(10),
+            ..Timings::default()
+        });
+        let runs = AtomicUsize::new(0);
+        let res = bench_runs(quick, BenchConfig::DEFAULT, &runs);
+        assert_eq!((203, 200), (runs.load(Ordering::Relaxed), res.samples));
+    }
+
+    // All in one test, as only one thing can gag stdout at a time
+    #[test]
+    fn isolated() {
+        let timeout = Duration::from_millis(200);
+        assert_eq!(Ok(5), isolate(|| 2 + 3, timeout));
+        assert_eq!(
+            Err(Failure::Panic(String::from("bad input"))),
+            isolate(|| -> u8 { panic!("bad input") }, timeout)
+        );
+        assert_eq!(
+            Err(Failure::Panic(String::from("line 7"))),
+            isolate(|| -> u8 { panic!("line {}", 7) }, timeout)
+        );
+        assert_eq!(
+            Err(Failure::Timeout(timeout)),
+            isolate(|| thread::sleep(Duration::from_secs(5)), timeout)
+        );
+
+        let day = |number, solve| Day {
+            number,
+            name: "Day",
+            title: "",
+            input: "",
+            solve,
+            example: |_, _| String::new(),
+            time: |_| Timings::default(),
+        };
+        let days = [
+            day(1, solved),
+            day(2, broken),
+            day(3, solved),
+            day(1, solved),
+        ];
+        let res = solve_all(&days, 2, timeout);
+        assert_eq!(4, res.len());
+        assert_eq!(Some("2"), res[0].as_ref().ok().map(|(a, _)| a.part(2)));
+        assert_eq!(
+            Some(&Failure::Panic(String::from("broken"))),
+            res[1].as_ref().err()
+        );
+        assert!(res[2].is_ok() && res[3].is_ok());
+    }
+
+    /// How many of the `busy` days are running at once, & the most there have ever been
+    static BUSY: AtomicUsize = AtomicUsize::new(0);
+    static MOST_BUSY: AtomicUsize = AtomicUsize::new(0);
+
+    fn busy(_: &'static str) -> Answers {
+        let now = BUSY.fetch_add(1, Ordering::SeqCst) + 1;
+        MOST_BUSY.fetch_max(now, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(60));
+        BUSY.fetch_sub(1, Ordering::SeqCst);
+        solved("")
+    }
+
+    fn stuck(_: &'static str) -> Answers {
+        thread::sleep(Duration::from_millis(200));
+        solved("")
+    }
+
+    #[test]
+    fn retired_workers() {
+        let day = |solve| Day {
+            number: 1,
+            name: "Day",
+            title: "",
+            input: "",
+            solve,
+            example: |_, _| String::new(),
+            time: |_| Timings::default(),
+        };
+        let mut days = vec![day(stuck)];
+        days.extend([day(busy); 6]);
+        let res = solve_all(&days, 1, Duration::from_millis(100));
+        assert!(matches!(res[0], Err(Failure::Timeout(_))));
+        assert!(res[1..].iter().all(Result::is_ok));
+        // The stuck day's thread finishes part way through, but doesn't take another day
+        assert_eq!(1, MOST_BUSY.load(Ordering::SeqCst));
+    }
 }
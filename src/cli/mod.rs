@@ -0,0 +1,768 @@
+use crate::bench::BenchConfig;
+use crate::days::{self, Day, Year};
+use crate::report::Format;
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+pub enum Command {
+    Interactive,
+    Help,
+    Run(RunOptions),
+    Compare(CompareOptions),
+    /// Benchmark one day at two revisions
+    CompareRev {
+        year: Year,
+        day: usize,
+        base: String,
+        /// Defaults to `HEAD`
+        new: String,
+    },
+    Verify {
+        selection: Selection,
+        record: bool,
+        /// Number of days to check at once, 1 checks them one after the other
+        jobs: usize,
+        /// How long a day gets before it's given up on
+        timeout: Duration,
+    },
+    Examples(Selection),
+    New {
+        year: u16,
+        day: u8,
+    },
+}
+
+pub struct Selection {
+    pub years: Vec<Year>,
+    pub days: Option<Vec<usize>>,
+}
+
+impl Selection {
+    /// The days to run for the given year, dropping any that have not been written yet
+    pub fn days_for(&self, year: Year) -> Vec<Day> {
+        year.days()
+            .iter()
+            .filter(|d| {
+                self.days
+                    .as_ref()
+                    .is_none_or(|days| days.contains(&d.number))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// A single year and a single day was asked for
+    pub fn single(&self) -> Option<(Year, usize)> {
+        match (self.years.as_slice(), self.days.as_deref()) {
+            (&[year], Some(&[day])) => Some((year, day)),
+            _ => None,
+        }
+    }
+}
+
+pub struct RunOptions {
+    pub selection: Selection,
+    pub format: Format,
+    /// How many of the slowest days to list when running more than one year
+    pub slowest: usize,
+    /// How long a day gets before it's given up on
+    pub timeout: Duration,
+    /// Profile how much memory each day allocates, as well as the run time
+    pub memory: bool,
+    pub bench: BenchConfig,
+    /// Pin the runner to this CPU, so the days don't get moved about mid run
+    pub pin: Option<usize>,
+    /// Run the day on this input, rather than the one it was written for
+    pub input: Option<PathBuf>,
+}
+
+const TIMEOUT_SECS: u64 = 60;
+/// How long a day gets before it's given up on, unless asked otherwise. Long enough for the
+/// slowest days, which take a few seconds for each of their runs
+pub const TIMEOUT: Duration = Duration::from_secs(TIMEOUT_SECS);
+
+impl RunOptions {
+    /// Run the selection with all the defaults, as the interactive menu does
+    pub const fn new(selection: Selection) -> Self {
+        Self {
+            selection,
+            format: Format::Table,
+            slowest: 10,
+            timeout: TIMEOUT,
+            memory: false,
+            bench: BenchConfig::DEFAULT,
+            pin: None,
+            input: None,
+        }
+    }
+}
+
+pub struct CompareOptions {
+    /// Commit to compare against, defaults to the previous run of each day
+    pub baseline: Option<String>,
+    /// How much slower (in percent) a day can get before it's flagged
+    pub threshold: f64,
+}
+
+#[derive(Debug)]
+pub struct ArgError(String);
+
+impl Display for ArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn parse<I>(args: I) -> Result<Command, ArgError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let Some(cmd) = args.next() else {
+        return Ok(Command::Interactive);
+    };
+
+    match cmd.as_str() {
+        "h" | "help" | "-h" | "--help" => Ok(Command::Help),
+        "run" => parse_run(args),
+        "compare" => parse_compare(args),
+        "compare-rev" => parse_compare_rev(args),
+        "verify" => parse_verify(args),
+        "examples" => parse_examples(args),
+        "new" => parse_new(args),
+        other => Err(ArgError(format!("Unknown command: '{other}'"))),
+    }
+}
+
+fn parse_run<I>(args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut format = None;
+    let mut slowest = None;
+    let mut timeout = None;
+    let mut memory = false;
+    let mut warmup = None;
+    let mut target = None;
+    let mut min_runs = None;
+    let mut max_runs = None;
+    let mut pin = None;
+    let mut input = None;
+    let selection = parse_selection(args, |arg, args| match arg {
+        "-f" | "--format" => {
+            let value = next_value(arg, args)?;
+            let Ok(value) = Format::try_from(value.as_str()) else {
+                return Err(ArgError(format!("'{value}' is not a valid format")));
+            };
+            set_once(&mut format, value, "formats")
+        }
+        "-s" | "--slowest" => set_once(&mut slowest, parse_count(arg, args)?, "slowest days"),
+        "-t" | "--timeout" => set_once(&mut timeout, parse_secs(arg, args)?, "timeouts"),
+        "-m" | "--memory" if cfg!(feature = "memory") => {
+            memory = true;
+            Ok(())
+        }
+        "-m" | "--memory" => Err(ArgError(String::from(
+            "Profiling memory needs the runner to be built with `--features memory`",
+        ))),
+        "-w" | "--warmup" => set_once(&mut warmup, parse_count(arg, args)?, "warmup runs"),
+        "--target" => set_once(&mut target, parse_secs(arg, args)?, "target times"),
+        "--min-runs" => set_once(&mut min_runs, parse_count(arg, args)?, "minimum runs"),
+        "--max-runs" => set_once(&mut max_runs, parse_count(arg, args)?, "maximum runs"),
+        "--pin" => set_once(&mut pin, parse_count(arg, args)?, "CPUs to pin to"),
+        "-i" | "--input" => set_once(&mut input, PathBuf::from(next_value(arg, args)?), "inputs"),
+        _ => Err(ArgError(format!("Unknown option for run: '{arg}'"))),
+    })?;
+    let Some(selection) = selection else {
+        return Err(ArgError(String::from("No year given to run")));
+    };
+    if input.is_some() && selection.single().is_none() {
+        return Err(ArgError(String::from(
+            "An input file can only be given when running a single day",
+        )));
+    }
+
+    let defaults = RunOptions::new(selection);
+    let bench = BenchConfig {
+        warmup: warmup.unwrap_or(defaults.bench.warmup),
+        target: target.unwrap_or(defaults.bench.target),
+        min_runs: min_runs.unwrap_or(defaults.bench.min_runs),
+        max_runs: max_runs.unwrap_or(defaults.bench.max_runs),
+    };
+    if bench.min_runs == 0 || bench.min_runs > bench.max_runs {
+        return Err(ArgError(format!(
+            "The runs need to be at least 1, with the minimum no more than the maximum, got {} to {}",
+            bench.min_runs, bench.max_runs
+        )));
+    }
+    Ok(Command::Run(RunOptions {
+        format: format.unwrap_or(defaults.format),
+        slowest: slowest.unwrap_or(defaults.slowest),
+        timeout: timeout.unwrap_or(defaults.timeout),
+        memory,
+        bench,
+        pin,
+        input,
+        ..defaults
+    }))
+}
+
+fn next_value<I>(arg: &str, args: &mut I) -> Result<String, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| ArgError(format!("Missing value for '{arg}'")))
+}
+
+fn parse_count<I>(arg: &str, args: &mut I) -> Result<usize, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let value = next_value(arg, args)?;
+    value
+        .parse()
+        .map_err(|_| ArgError(format!("'{value}' is not a number")))
+}
+
+/// A number of seconds, which can have a fraction & an `s` on the end, e.g. `2.5s`
+fn parse_secs<I>(arg: &str, args: &mut I) -> Result<Duration, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let value = next_value(arg, args)?;
+    match value.trim_end_matches('s').parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(ArgError(format!(
+            "'{value}' is not a valid number of seconds"
+        ))),
+    }
+}
+
+fn parse_verify<I>(args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut record = false;
+    let mut jobs = None;
+    let mut timeout = None;
+    let selection = parse_selection(args, |arg, args| match arg {
+        "-r" | "--record" => {
+            record = true;
+            Ok(())
+        }
+        "-p" | "--parallel" => {
+            let cores = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+            set_once(&mut jobs, cores, "parallel options")
+        }
+        "-j" | "--jobs" => match parse_count(arg, args)? {
+            0 => Err(ArgError(String::from("At least 1 job is needed"))),
+            n => set_once(&mut jobs, n, "parallel options"),
+        },
+        "-t" | "--timeout" => set_once(&mut timeout, parse_secs(arg, args)?, "timeouts"),
+        _ => Err(ArgError(format!("Unknown option for verify: '{arg}'"))),
+    })?;
+    // Verify everything if nothing is picked out
+    let selection = selection.unwrap_or_else(|| Selection {
+        years: days::YEARS.to_vec(),
+        days: None,
+    });
+    Ok(Command::Verify {
+        selection,
+        record,
+        jobs: jobs.unwrap_or(1),
+        timeout: timeout.unwrap_or(TIMEOUT),
+    })
+}
+
+fn parse_examples<I>(args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let selection = parse_selection(args, |arg, _| {
+        Err(ArgError(format!("Unknown option for examples: '{arg}'")))
+    })?;
+    // Check every day if nothing is picked out
+    Ok(Command::Examples(selection.unwrap_or_else(|| Selection {
+        years: days::YEARS.to_vec(),
+        days: None,
+    })))
+}
+
+fn parse_new<I>(mut args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let (Some(year), Some(day)) = (args.next(), args.next()) else {
+        return Err(ArgError(String::from("A new day needs a year and a day")));
+    };
+    if let Some(arg) = args.next() {
+        return Err(ArgError(format!("Unknown option for new: '{arg}'")));
+    }
+    // The year can be past the last one written so far, but the folders only fit 4 digit years
+    let year = parse_number(&year, days::YEARS[0].number(), 9999)?;
+    let day = parse_number(&day, 1, 25)?;
+    Ok(Command::New {
+        year,
+        day: u8::try_from(day).expect("days are at most 25"),
+    })
+}
+
+/// Read the years & days to run, handing any other options off to `other`
+///
+/// Returns `None` if no years were given
+fn parse_selection<I, F>(mut args: I, mut other: F) -> Result<Option<Selection>, ArgError>
+where
+    I: Iterator<Item = String>,
+    F: FnMut(&str, &mut I) -> Result<(), ArgError>,
+{
+    let mut years = None;
+    let mut days = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-d" | "--days" => {
+                let Some(spec) = args.next() else {
+                    return Err(ArgError(format!("Missing value for '{arg}'")));
+                };
+                set_once(&mut days, parse_days(&spec)?, "days")?;
+            }
+            flag if flag.starts_with('-') => other(flag, &mut args)?,
+            spec if years.is_none() => years = Some(parse_years(spec)?),
+            spec => set_once(&mut days, parse_days(spec)?, "days")?,
+        }
+    }
+
+    let Some(years) = years else {
+        if days.is_some() {
+            return Err(ArgError(String::from("Days were given without a year")));
+        }
+        return Ok(None);
+    };
+    let selection = Selection { years, days };
+
+    if let Some((year, day)) = selection.single() {
+        if days::get(year, day).is_none() {
+            return Err(ArgError(format!("Day {day} is not available for {year}")));
+        }
+    }
+
+    Ok(Some(selection))
+}
+
+fn parse_compare<I>(mut args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut opts = CompareOptions {
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    while let Some(arg) = args.next() {
+        let Some(value) = args.next() else {
+            return Err(ArgError(format!("Missing value for '{arg}'")));
+        };
+        match arg.as_str() {
+            "-b" | "--baseline" => opts.baseline = Some(value),
+            "-t" | "--threshold" => {
+                opts.threshold = match value.trim_end_matches('%').parse::<f64>() {
+                    Ok(t) if t >= 0.0 => t,
+                    _ => return Err(ArgError(format!("'{value}' is not a valid threshold"))),
+                }
+            }
+            _ => return Err(ArgError(format!("Unknown option for compare: '{arg}'"))),
+        }
+    }
+
+    Ok(Command::Compare(opts))
+}
+
+fn parse_compare_rev<I>(mut args: I) -> Result<Command, ArgError>
+where
+    I: Iterator<Item = String>,
+{
+    let (Some(year), Some(day), Some(base)) = (args.next(), args.next(), args.next()) else {
+        return Err(ArgError(String::from(
+            "compare-rev needs a year, a day and a revision to compare against",
+        )));
+    };
+    let new = args.next().unwrap_or_else(|| String::from("HEAD"));
+    if let Some(arg) = args.next() {
+        return Err(ArgError(format!("Unknown option for compare-rev: '{arg}'")));
+    }
+    let first = days::YEARS[0].number();
+    let last = days::YEARS[days::YEARS.len() - 1].number();
+    let year = Year::try_from(parse_number(&year, first, last)?)
+        .expect("number is bounded to the valid years");
+    // The day only has to exist at the two revisions, not in this checkout
+    let day = usize::from(parse_number(&day, 1, 25)?);
+    Ok(Command::CompareRev {
+        year,
+        day,
+        base,
+        new,
+    })
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, name: &str) -> Result<(), ArgError> {
+    if slot.is_some() {
+        return Err(ArgError(format!(
+            "The {name} have been given more than once"
+        )));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn parse_years(spec: &str) -> Result<Vec<Year>, ArgError> {
+    if spec == "all" {
+        return Ok(days::YEARS.to_vec());
+    }
+    let first = days::YEARS[0].number();
+    let last = days::YEARS[days::YEARS.len() - 1].number();
+    let numbers = parse_list(spec, first, last)?;
+    Ok(numbers
+        .into_iter()
+        .map(|n| Year::try_from(n).expect("list is bounded to the valid years"))
+        .collect())
+}
+
+fn parse_days(spec: &str) -> Result<Vec<usize>, ArgError> {
+    let numbers = parse_list(spec, 1, 25)?;
+    Ok(numbers.into_iter().map(usize::from).collect())
+}
+
+/// Parse a comma separated list of numbers and ranges, e.g. `1,3,5-7,10..=12`
+///
+/// Ranges can be written as `a-b` or `a..=b` (both inclusive) or `a..b` (exclusive). The output
+/// is sorted with duplicates removed
+fn parse_list(spec: &str, min: u16, max: u16) -> Result<Vec<u16>, ArgError> {
+    let mut out = Vec::new();
+    for part in spec.split(',').filter(|p| !p.is_empty()) {
+        let (start, end) = if let Some((a, b)) = part.split_once("..=") {
+            (parse_number(a, min, max)?, parse_number(b, min, max)?)
+        } else if let Some((a, b)) = part.split_once("..") {
+            let b = parse_number(b, min, max + 1)?;
+            (parse_number(a, min, max)?, b - 1)
+        } else if let Some((a, b)) = part.split_once('-') {
+            (parse_number(a, min, max)?, parse_number(b, min, max)?)
+        } else {
+            let n = parse_number(part, min, max)?;
+            (n, n)
+        };
+        if start > end {
+            return Err(ArgError(format!("The range '{part}' is empty")));
+        }
+        out.extend(start..=end);
+    }
+    if out.is_empty() {
+        return Err(ArgError(format!("Nothing selected by '{spec}'")));
+    }
+    out.sort_unstable();
+    out.dedup();
+    Ok(out)
+}
+
+fn parse_number(s: &str, min: u16, max: u16) -> Result<u16, ArgError> {
+    match s.trim().parse() {
+        Ok(n) if (min..=max).contains(&n) => Ok(n),
+        Ok(n) => Err(ArgError(format!(
+            "{n} is out of range, expected {min} to {max}"
+        ))),
+        Err(_) => Err(ArgError(format!("'{s}' is not a number"))),
+    }
+}
+
+pub fn usage() {
+    println!("Usage:");
+    println!("  aoc-rs                          Pick the year & day from the interactive menu");
+    println!("  aoc-rs run <YEARS> [DAYS]       Run the selected days without any prompts");
+    println!("  aoc-rs compare [OPTIONS]        Flag days that have got slower between runs");
+    println!("  aoc-rs compare-rev <YEAR> <DAY> <BASE> [NEW]");
+    println!(
+        "                                  Benchmark a day at two git revisions, NEW defaults"
+    );
+    println!("                                  to HEAD");
+    println!("  aoc-rs verify [YEARS] [DAYS]    Check the answers against the ones on record");
+    println!("  aoc-rs examples [YEARS] [DAYS]  Check each day's examples against their answers");
+    println!("  aoc-rs new <YEAR> <DAY>         Create a new day from the template");
+    println!("  aoc-rs help                     Show this message");
+    println!();
+    println!("Options for run:");
+    println!("  -d, --days <DAYS>       The days to run in each year, defaults to all days");
+    println!(
+        "  -f, --format <FORMAT>   How to print the results, one of table (the default), json,"
+    );
+    println!("                          csv or markdown. Other than the table, these include");
+    println!("                          whether each answer matches the one on record");
+    println!("  -s, --slowest <N>       How many of the slowest days to list when running more");
+    println!("                          than one year, defaults to 10");
+    println!("  -t, --timeout <SECS>    Give up on a day if it's still going after this long,");
+    println!("                          defaults to 60s");
+    println!("  -m, --memory            Also profile the memory each day allocates, on a run of");
+    println!("                          its own. Needs the runner built with `--features memory`");
+    println!("  -w, --warmup <N>        Runs to throw away before timing each day, defaults to 3");
+    println!("      --target <SECS>     Roughly how long to spend timing each day, defaults to 2s");
+    println!("      --min-runs <N>      Fewest timed runs of each day, defaults to 1");
+    println!("      --max-runs <N>      Most timed runs of each day, defaults to 1000");
+    println!("      --pin <CPU>         Keep the runner on this CPU (Linux only)");
+    println!(
+        "  -i, --input <PATH>      Run a single day on this input file instead of its own, and"
+    );
+    println!("                          show the answers. These runs aren't saved to the history");
+    println!();
+    println!("Options for compare:");
+    println!("  -b, --baseline <COMMIT>   Compare to the runs from this commit, defaults to the");
+    println!("                            previous run of each day");
+    println!("  -t, --threshold <PCNT>    Flag days that are this much slower, defaults to 10%");
+    println!();
+    println!("Options for verify:");
+    println!("  -d, --days <DAYS>       The days to check in each year, defaults to all days");
+    println!("  -r, --record            Save the answers for any days that don't have one on");
+    println!("                          record");
+    println!("  -p, --parallel          Check the days on all the cores at once. Much quicker,");
+    println!("                          but the times are only a rough guide");
+    println!("  -j, --jobs <N>          Check the days on N threads at once");
+    println!("  -t, --timeout <SECS>    Give up on a day if it's still going after this long,");
+    println!("                          defaults to 60s");
+    println!();
+    println!("Options for examples:");
+    println!("  -d, --days <DAYS>   The days to check in each year, defaults to all days");
+    println!("The answers to each day's examples go in src/examples.txt, one per line as");
+    println!("'<file> <part> <answer>', e.g. 'test.txt 1 142'");
+    println!();
+    println!("YEARS and DAYS are a single number, a range or a comma separated list of either");
+    println!("YEARS can also be 'all' to pick every year");
+    println!("Ranges can be written as 1-10, 1..=10 or 1..11");
+    println!();
+    println!("Examples:");
+    println!("  aoc-rs run 2023 5");
+    println!("  aoc-rs run 2017");
+    println!("  aoc-rs run 2015..=2017 --days 1-10");
+    println!("  aoc-rs run 2022 --format markdown");
+    println!("  aoc-rs run all --slowest 5 --timeout 10");
+    println!("  aoc-rs run 2020 --warmup 10 --target 5 --pin 2");
+    println!("  aoc-rs run 2023 5 --input ~/their_input.txt");
+    println!("  aoc-rs compare --baseline 1a2b3c4 --threshold 5");
+    println!("  aoc-rs compare-rev 2016 14 main my-rewrite");
+    println!("  aoc-rs verify 2016 --record");
+    println!("  aoc-rs verify --parallel");
+    println!("  aoc-rs examples 2023 --days 20");
+    println!("  aoc-rs new 2025 10");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(vec![5], parse_list("5", 1, 25).unwrap());
+        assert_eq!(vec![1, 2, 3], parse_list("1-3", 1, 25).unwrap());
+        assert_eq!(vec![1, 2, 3], parse_list("1..=3", 1, 25).unwrap());
+        assert_eq!(vec![1, 2], parse_list("1..3", 1, 25).unwrap());
+        assert_eq!(vec![1, 2, 5, 7], parse_list("7,1-2,5,2", 1, 25).unwrap());
+        assert_eq!(vec![24, 25], parse_list("24..26", 1, 25).unwrap());
+        assert!(parse_list("0", 1, 25).is_err());
+        assert!(parse_list("26", 1, 25).is_err());
+        assert!(parse_list("5-3", 1, 25).is_err());
+        assert!(parse_list("x", 1, 25).is_err());
+        assert!(parse_list("", 1, 25).is_err());
+    }
+
+    #[test]
+    fn commands() {
+        assert!(matches!(parse(args("")), Ok(Command::Interactive)));
+        assert!(matches!(parse(args("--help")), Ok(Command::Help)));
+        assert!(parse(args("walk 2015")).is_err());
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run 2015 1 2")).is_err());
+        assert!(parse(args("run 2015 --days")).is_err());
+
+        let Ok(Command::Run(opts)) = parse(args("run 2015..=2017 --days 1-10")) else {
+            panic!("should parse");
+        };
+        let sel = opts.selection;
+        assert_eq!(Format::Table, opts.format);
+        assert_eq!(10, opts.slowest);
+        assert_eq!(TIMEOUT, opts.timeout);
+        assert!(!opts.memory);
+        assert_eq!(3, sel.years.len());
+        assert_eq!(Some((1..=10).collect()), sel.days);
+        assert!(sel.single().is_none());
+
+        let Ok(Command::Run(opts)) = parse(args("run 2015 4")) else {
+            panic!("should parse");
+        };
+        assert!(matches!(opts.selection.single(), Some((Year::Y2015, 4))));
+
+        let Ok(Command::Run(opts)) = parse(args("run 2015 --format csv --timeout 2.5s")) else {
+            panic!("should parse");
+        };
+        assert_eq!(Format::Csv, opts.format);
+        assert_eq!(Duration::from_millis(2500), opts.timeout);
+        assert!(parse(args("run 2015 --timeout 0")).is_err());
+
+        let Ok(Command::Run(opts)) = parse(args(
+            "run 2015 -w 0 --target 0.5 --min-runs 5 --max-runs 5 --pin 1",
+        )) else {
+            panic!("should parse");
+        };
+        let bench = BenchConfig {
+            warmup: 0,
+            target: Duration::from_millis(500),
+            min_runs: 5,
+            max_runs: 5,
+        };
+        assert_eq!(bench, opts.bench);
+        assert_eq!(Some(1), opts.pin);
+        assert!(parse(args("run 2015 --min-runs 10 --max-runs 5")).is_err());
+        assert!(parse(args("run 2015 --max-runs 0")).is_err());
+        assert!(parse(args("run 2015 --pin")).is_err());
+
+        let Ok(Command::Run(opts)) = parse(args("run 2015 7 --input big.txt")) else {
+            panic!("should parse");
+        };
+        assert_eq!(Some(PathBuf::from("big.txt")), opts.input);
+        assert!(parse(args("run 2015 --input big.txt")).is_err());
+        assert!(parse(args("run 2015 1-2 -i big.txt")).is_err());
+        assert!(parse(args("run 2015 --format xml")).is_err());
+        assert!(parse(args("run 2015 -f csv -f json")).is_err());
+
+        let Ok(Command::Run(opts)) = parse(args("run all -s 3")) else {
+            panic!("should parse");
+        };
+        assert_eq!(days::YEARS.len(), opts.selection.years.len());
+        assert_eq!(3, opts.slowest);
+        assert!(!opts.memory);
+        // Only the runner with the counting allocator can profile memory
+        assert_eq!(
+            cfg!(feature = "memory"),
+            parse(args("run all --memory"))
+                .is_ok_and(|cmd| matches!(cmd, Command::Run(opts) if opts.memory))
+        );
+        assert!(parse(args("run all --slowest x")).is_err());
+    }
+
+    #[test]
+    fn compare() {
+        let Ok(Command::Compare(opts)) = parse(args("compare")) else {
+            panic!("should parse");
+        };
+        assert!(opts.baseline.is_none());
+
+        let Ok(Command::Compare(opts)) = parse(args("compare -b abc123 --threshold 5%")) else {
+            panic!("should parse");
+        };
+        assert_eq!(Some("abc123"), opts.baseline.as_deref());
+        assert!((opts.threshold - 5.0).abs() < f64::EPSILON);
+
+        assert!(parse(args("compare --threshold")).is_err());
+        assert!(parse(args("compare --threshold -5")).is_err());
+        assert!(parse(args("compare --days 5")).is_err());
+    }
+
+    #[test]
+    fn compare_rev() {
+        let Ok(Command::CompareRev {
+            year,
+            day,
+            base,
+            new,
+        }) = parse(args("compare-rev 2016 14 main"))
+        else {
+            panic!("should parse");
+        };
+        assert_eq!(Year::Y2016, year);
+        assert_eq!(14, day);
+        assert_eq!("main", base);
+        assert_eq!("HEAD", new);
+
+        assert!(matches!(
+            parse(args("compare-rev 2016 14 main abc123")),
+            Ok(Command::CompareRev { new, .. }) if new == "abc123"
+        ));
+        assert!(parse(args("compare-rev 2016 14")).is_err());
+        assert!(parse(args("compare-rev 2016 26 main")).is_err());
+        assert!(parse(args("compare-rev 2016 14 a b c")).is_err());
+    }
+
+    #[test]
+    fn verify() {
+        let Ok(Command::Verify {
+            selection,
+            record,
+            jobs,
+            timeout,
+        }) = parse(args("verify"))
+        else {
+            panic!("should parse");
+        };
+        assert_eq!(days::YEARS.len(), selection.years.len());
+        assert!(!record);
+        assert_eq!(1, jobs);
+        assert_eq!(TIMEOUT, timeout);
+
+        let Ok(Command::Verify {
+            selection, record, ..
+        }) = parse(args("verify 2016 -r"))
+        else {
+            panic!("should parse");
+        };
+        assert_eq!(1, selection.years.len());
+        assert!(record);
+
+        let Ok(Command::Verify { jobs, .. }) = parse(args("verify 2016 --jobs 4")) else {
+            panic!("should parse");
+        };
+        assert_eq!(4, jobs);
+        let Ok(Command::Verify { timeout, .. }) = parse(args("verify -t 2.5")) else {
+            panic!("should parse");
+        };
+        assert_eq!(Duration::from_millis(2500), timeout);
+        assert!(parse(args("verify -t 0")).is_err());
+        assert!(parse(args("verify --parallel")).is_ok());
+        assert!(parse(args("verify --jobs 0")).is_err());
+        assert!(parse(args("verify -p -j 2")).is_err());
+
+        assert!(parse(args("verify --days 5")).is_err());
+        assert!(parse(args("verify 2016 --bad")).is_err());
+        assert!(parse(args("run 2016 --record")).is_err());
+    }
+
+    #[test]
+    fn examples() {
+        let Ok(Command::Examples(selection)) = parse(args("examples")) else {
+            panic!("should parse");
+        };
+        assert_eq!(days::YEARS.len(), selection.years.len());
+
+        let Ok(Command::Examples(selection)) = parse(args("examples 2015 -d 1-3")) else {
+            panic!("should parse");
+        };
+        assert_eq!(vec![Year::Y2015], selection.years);
+        assert_eq!(Some(vec![1, 2, 3]), selection.days);
+        assert!(parse(args("examples 2015 --record")).is_err());
+    }
+
+    #[test]
+    fn new() {
+        assert!(matches!(
+            parse(args("new 2030 7")),
+            Ok(Command::New { year: 2030, day: 7 })
+        ));
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("new 2025")).is_err());
+        assert!(parse(args("new 2025 26")).is_err());
+        assert!(parse(args("new 2014 1")).is_err());
+        assert!(parse(args("new 2025 1 2")).is_err());
+    }
+}
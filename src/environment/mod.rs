@@ -0,0 +1,138 @@
+use crate::history;
+use std::fmt::Display;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::process::Command;
+use std::thread;
+
+/// The machine & build the timings were taken on, so reports from different machines can be
+/// compared fairly
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    pub cpu: String,
+    /// Logical cores, i.e. including any hyperthreads
+    pub cores: usize,
+    /// The CPU frequency governor, only known on Linux
+    pub governor: Option<String>,
+    pub rustc: &'static str,
+    pub profile: &'static str,
+    /// Comma separated, as cargo gives them
+    pub target_features: &'static str,
+    pub commit: String,
+    /// Whether there were uncommitted changes to tracked files
+    pub dirty: bool,
+}
+
+impl Environment {
+    /// Everything is read fresh, so it's best done once per run
+    pub fn detect() -> Self {
+        Self {
+            cpu: fs::read_to_string("/proc/cpuinfo")
+                .ok()
+                .and_then(|info| cpu_model(&info))
+                .unwrap_or_else(|| String::from("unknown")),
+            cores: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            governor: fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor")
+                .ok()
+                .map(|g| g.trim().to_owned()),
+            rustc: env!("AOC_RUSTC_VERSION"),
+            profile: env!("AOC_PROFILE"),
+            target_features: env!("AOC_TARGET_FEATURES"),
+            commit: history::current_commit(),
+            dirty: is_dirty(),
+        }
+    }
+
+    /// Every field as a (name, value) pair, in the order they're reported in
+    pub fn fields(&self) -> [(&'static str, String); 8] {
+        [
+            ("cpu", self.cpu.clone()),
+            ("cores", self.cores.to_string()),
+            ("governor", self.governor.clone().unwrap_or_default()),
+            ("rustc", self.rustc.to_owned()),
+            ("profile", self.profile.to_owned()),
+            ("target_features", self.target_features.to_owned()),
+            ("commit", self.commit.clone()),
+            ("dirty", self.dirty.to_string()),
+        ]
+    }
+}
+
+/// One line summary, leaving out the target features as there are usually dozens of them
+impl Display for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {} cores", self.cpu, self.cores)?;
+        if let Some(governor) = &self.governor {
+            write!(f, " ({governor} governor)")?;
+        }
+        write!(
+            f,
+            ", {}, {} build, commit {}",
+            self.rustc, self.profile, self.commit
+        )?;
+        if self.dirty {
+            write!(f, " with uncommitted changes")?;
+        }
+        Ok(())
+    }
+}
+
+/// The CPU's name from `/proc/cpuinfo`, where x86 calls it the model name & some ARM boards only
+/// name the hardware
+fn cpu_model(cpuinfo: &str) -> Option<String> {
+    ["model name", "Hardware", "Model"].iter().find_map(|key| {
+        cpuinfo.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            (name.trim() == *key && !value.trim().is_empty()).then(|| value.trim().to_owned())
+        })
+    })
+}
+
+fn is_dirty() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .is_some_and(|out| !out.stdout.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpu_names() {
+        let x86 = "processor\t: 0\nvendor_id\t: AuthenticAMD\nmodel\t\t: 33\n\
+                   model name\t: AMD Ryzen 7 5800X 8-Core Processor\n";
+        assert_eq!(
+            Some(String::from("AMD Ryzen 7 5800X 8-Core Processor")),
+            cpu_model(x86)
+        );
+        let arm =
+            "processor\t: 0\nBogoMIPS\t: 108.00\n\nHardware\t: BCM2835\nModel\t: Raspberry Pi 4";
+        assert_eq!(Some(String::from("BCM2835")), cpu_model(arm));
+        assert_eq!(None, cpu_model("processor\t: 0\n"));
+    }
+
+    #[test]
+    fn summary() {
+        let env = Environment {
+            cpu: String::from("Some CPU"),
+            cores: 8,
+            governor: Some(String::from("performance")),
+            rustc: "rustc 1.80.0",
+            profile: "release",
+            target_features: "fxsr,sse,sse2",
+            commit: String::from("abc1234"),
+            dirty: true,
+        };
+        assert_eq!(
+            "Some CPU, 8 cores (performance governor), rustc 1.80.0, release build, commit abc1234 \
+             with uncommitted changes",
+            env.to_string()
+        );
+        assert_eq!(("dirty", String::from("true")), env.fields()[7]);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::answers;
+use crate::bench;
+use crate::days::{Day, Year};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+/// Sidecar file in each day's `src` folder with the answers to its examples
+const EXPECTED_FILE: &str = "examples.txt";
+
+/// One of a day's example files & the answer it should give for one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+}
+
+/// A case once it's been run, with the answer the day gave or why it didn't give one
+pub struct Checked {
+    pub case: Case,
+    pub actual: Result<String, String>,
+}
+
+impl Checked {
+    pub fn passed(&self) -> bool {
+        self.actual
+            .as_ref()
+            .is_ok_and(|actual| *actual == self.case.expected)
+    }
+}
+
+/// How a day got on with its examples, for the matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There are example files, but no answers for them in the sidecar
+    Unchecked,
+    /// No example files at all
+    Missing,
+}
+
+impl Status {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "✓",
+            Self::Fail => "✗",
+            Self::Unchecked => "?",
+            Self::Missing => "·",
+        }
+    }
+}
+
+/// Run all of a day's example cases, with each part of each one on a thread of its own
+pub fn check_day(year: Year, day: Day, timeout: Duration) -> io::Result<(Status, Vec<Checked>)> {
+    let dir = folder(year, day.number);
+    let cases = match fs::read_to_string(dir.join(EXPECTED_FILE)) {
+        Ok(text) => parse(&text).map_err(|e| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("{year}/day_{:02}/src/{EXPECTED_FILE}: {e}", day.number),
+            )
+        })?,
+        Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    if cases.is_empty() {
+        let status = if example_files(&dir)?.is_empty() {
+            Status::Missing
+        } else {
+            Status::Unchecked
+        };
+        return Ok((status, Vec::new()));
+    }
+
+    let checked = run_cases(&dir, day, cases, timeout);
+    let status = if checked.iter().all(Checked::passed) {
+        Status::Pass
+    } else {
+        Status::Fail
+    };
+    Ok((status, checked))
+}
+
+/// Run each case on its example file from `dir`, with a file that can't be read failing its case
+fn run_cases(dir: &Path, day: Day, cases: Vec<Case>, timeout: Duration) -> Vec<Checked> {
+    cases
+        .into_iter()
+        .map(|case| {
+            let actual = match fs::read_to_string(dir.join(&case.file)) {
+                Ok(input) => {
+                    let input: &'static str = Box::leak(input.into_boxed_str());
+                    let part = case.part;
+                    bench::isolate(move || (day.example)(input, part), timeout)
+                        .map_err(|e| e.to_string())
+                }
+                Err(e) => Err(format!("couldn't be read: {e}")),
+            };
+            Checked { case, actual }
+        })
+        .collect()
+}
+
+fn folder(year: Year, day: usize) -> PathBuf {
+    PathBuf::from(ROOT).join(format!("{year}/day_{day:02}/src"))
+}
+
+/// Names of the example inputs in a day's `src` folder, e.g. `test.txt` or `test_a.txt`
+///
+/// Empty files are left out, as they're only placeholders from days whose examples are written
+/// into their tests
+fn example_files(dir: &Path) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if let Some(name) = name.to_str().filter(|n| is_example(n)) {
+            if entry.metadata()?.len() > 0 {
+                files.push(name.to_owned());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn is_example(name: &str) -> bool {
+    name.strip_prefix("test")
+        .and_then(|n| n.strip_suffix(".txt"))
+        .is_some()
+}
+
+/// Read the cases from a sidecar file, one per line as `<file> <part> <expected answer>`
+///
+/// The answer is everything after the part, so it can have spaces in it. Screens that can't be
+/// read are written on one line with `\n` between rows, as they are in `answers.txt`. Blank lines
+/// & lines starting with `#` are skipped
+fn parse(text: &str) -> Result<Vec<Case>, String> {
+    let mut cases = Vec::new();
+    for (line, n) in text.lines().zip(1..) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let (Some(file), Some(part), Some(expected)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(format!("line {n} should be '<file> <part> <answer>'"));
+        };
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => {
+                return Err(format!(
+                    "line {n} has part '{part}', which should be 1 or 2"
+                ))
+            }
+        };
+        cases.push(Case {
+            file: file.to_owned(),
+            part,
+            expected: answers::unescape(expected.trim()),
+        });
+    }
+    Ok(cases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Timings;
+    use aoc_solution::Answers;
+
+    #[test]
+    fn sidecar() {
+        let text = "# file part answer\n\ntest.txt 1 142\ntest_b.txt\t2  ab cd \n";
+        assert_eq!(
+            Ok(vec![
+                Case {
+                    file: String::from("test.txt"),
+                    part: 1,
+                    expected: String::from("142"),
+                },
+                Case {
+                    file: String::from("test_b.txt"),
+                    part: 2,
+                    expected: String::from("ab cd"),
+                },
+            ]),
+            parse(text)
+        );
+        assert_eq!("#.\n.#", parse("test.txt 2 #.\\n.#").unwrap()[0].expected);
+        assert!(parse("test.txt 1").is_err());
+        assert!(parse("test.txt 3 5").is_err());
+    }
+
+    #[test]
+    fn example_names() {
+        assert!(is_example("test.txt"));
+        assert!(is_example("test2.txt"));
+        assert!(is_example("test_simple.txt"));
+        assert!(!is_example("input.txt"));
+        assert!(!is_example("test.rs"));
+    }
+
+    #[test]
+    fn unreadable_example() {
+        let dir = std::env::temp_dir().join(format!("aoc-rs-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("test.txt"), "abc").unwrap();
+        let day = Day {
+            number: 1,
+            name: "Day",
+            title: "",
+            input: "",
+            solve: |_| Answers {
+                part_one: String::new(),
+                part_two: String::new(),
+            },
+            example: |input, part| format!("{part}: {}", input.len()),
+            time: |_| Timings::default(),
+        };
+        let case = |file: &str| Case {
+            file: file.to_owned(),
+            part: 1,
+            expected: String::from("1: 3"),
+        };
+        let checked = run_cases(
+            &dir,
+            day,
+            vec![case("missing.txt"), case("test.txt")],
+            Duration::from_secs(1),
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(checked[0]
+            .actual
+            .as_ref()
+            .is_err_and(|e| e.starts_with("couldn't be read")));
+        assert!(checked[1].passed());
+    }
+}
@@ -0,0 +1,285 @@
+use crate::bench::BenchResult;
+use crate::days::Year;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Local (git ignored) file that every benchmark run gets appended to
+const HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench_history.csv");
+const HEADER: &str = "timestamp,commit,year,day,samples,min_ns,median_ns,mean_ns,std_dev_ns,p95_ns,max_ns,parse_ns,part_one_ns,part_two_ns,contended";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub commit: String,
+    pub year: u16,
+    pub day: usize,
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+    /// See `BenchResult::contended`
+    pub contended: bool,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.year,
+            self.day,
+            self.samples,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.std_dev.as_nanos(),
+            self.p95.as_nanos(),
+            self.max.as_nanos(),
+            self.parse.as_nanos(),
+            self.part_one.as_nanos(),
+            self.part_two.as_nanos(),
+            self.contended,
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut parts = line.split(',');
+        let mut next = || parts.next();
+        let timestamp = next()?.parse().ok()?;
+        let commit = next()?.to_owned();
+        let year = next()?.parse().ok()?;
+        let day = next()?.parse().ok()?;
+        let samples = next()?.parse().ok()?;
+        let mut nanos = || {
+            next()
+                .and_then(|n| n.parse().ok())
+                .map(Duration::from_nanos)
+        };
+        let mut rec = Self {
+            timestamp,
+            commit,
+            year,
+            day,
+            samples,
+            min: nanos()?,
+            median: nanos()?,
+            mean: nanos()?,
+            std_dev: nanos()?,
+            p95: nanos()?,
+            max: nanos()?,
+            // Runs saved before the steps were timed separately don't have these
+            parse: nanos().unwrap_or_default(),
+            part_one: nanos().unwrap_or_default(),
+            part_two: nanos().unwrap_or_default(),
+            contended: false,
+        };
+        // Nor do runs saved before they were marked
+        rec.contended = next() == Some("true");
+        Some(rec)
+    }
+}
+
+/// Append the results of a run to the history file, all stamped with the same time & commit
+pub fn record(year: Year, results: &[(usize, BenchResult)]) -> io::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = current_commit();
+    let year = year.number();
+
+    let path = Path::new(HISTORY_FILE);
+    let new_file = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if new_file {
+        writeln!(file, "{HEADER}")?;
+    }
+    for &(day, res) in results {
+        let rec = Record {
+            timestamp,
+            commit: commit.clone(),
+            year,
+            day,
+            samples: res.samples,
+            min: res.min,
+            median: res.median,
+            mean: res.mean,
+            std_dev: res.std_dev,
+            p95: res.p95,
+            max: res.max,
+            parse: res.phases.parse,
+            part_one: res.phases.part_one,
+            part_two: res.phases.part_two,
+            contended: res.contended,
+        };
+        writeln!(file, "{}", rec.to_line())?;
+    }
+    Ok(())
+}
+
+/// Read back every run in the history file, oldest first. Lines that can't be read are skipped
+pub fn load() -> io::Result<Vec<Record>> {
+    let file = match File::open(HISTORY_FILE) {
+        Ok(f) => f,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut records = Vec::new();
+    for line in BufReader::new(file).lines() {
+        if let Some(rec) = Record::from_line(&line?) {
+            records.push(rec);
+        }
+    }
+    Ok(records)
+}
+
+/// The most recent run of each day, keyed on year & day
+pub fn latest() -> io::Result<HashMap<(u16, usize), Record>> {
+    Ok(load()?.into_iter().map(|r| ((r.year, r.day), r)).collect())
+}
+
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map_or_else(|| String::from("unknown"), |s| s.trim().to_owned())
+}
+
+pub struct Comparison<'a> {
+    pub baseline: &'a Record,
+    pub current: &'a Record,
+}
+
+impl Comparison<'_> {
+    /// Percentage change in the median run time, positive is slower
+    pub fn change_pcnt(&self) -> f64 {
+        let base = self.baseline.median.as_secs_f64();
+        if base == 0.0 {
+            return 0.0;
+        }
+        100.0 * (self.current.median.as_secs_f64() - base) / base
+    }
+}
+
+/// Pair up the latest run of every day with a baseline run of the same day
+///
+/// If a baseline commit is given the latest run from that commit is used, otherwise it's the
+/// run that came just before the latest one. Days without a baseline are left out
+pub fn compare<'a>(records: &'a [Record], baseline: Option<&str>) -> Vec<Comparison<'a>> {
+    let mut by_day: HashMap<(u16, usize), Vec<&Record>> = HashMap::new();
+    for rec in records {
+        by_day.entry((rec.year, rec.day)).or_default().push(rec);
+    }
+
+    let mut out = by_day
+        .into_values()
+        .filter_map(|runs| {
+            let (&current, rest) = runs.split_last()?;
+            let &baseline = match baseline {
+                Some(commit) => rest
+                    .iter()
+                    .rev()
+                    .find(|r| r.commit.starts_with(commit) || commit.starts_with(&r.commit))?,
+                None => rest.last()?,
+            };
+            Some(Comparison { baseline, current })
+        })
+        .collect::<Vec<_>>();
+    out.sort_unstable_by_key(|c| (c.current.year, c.current.day));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rec(timestamp: u64, commit: &str, day: usize, median_us: u64) -> Record {
+        let d = Duration::from_micros(median_us);
+        Record {
+            timestamp,
+            commit: commit.to_owned(),
+            year: 2015,
+            day,
+            samples: 10,
+            min: d,
+            median: d,
+            mean: d,
+            std_dev: Duration::ZERO,
+            p95: d,
+            max: d,
+            parse: d / 5,
+            part_one: d / 5 * 2,
+            part_two: d / 5 * 2,
+            contended: false,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let r = rec(1_700_000_000, "abc1234", 3, 25);
+        let line = r.to_line();
+        assert_eq!(
+            "1700000000,abc1234,2015,3,10,25000,25000,25000,0,25000,25000,5000,10000,10000,false",
+            line
+        );
+        assert_eq!(Some(r), Record::from_line(&line));
+        let contended = Record {
+            contended: true,
+            ..rec(1_700_000_000, "abc1234", 3, 25)
+        };
+        assert_eq!(
+            Some(contended.clone()),
+            Record::from_line(&contended.to_line())
+        );
+
+        let old = Record::from_line("1,abc,2015,3,10,25000,25000,25000,0,25000,25000").unwrap();
+        assert_eq!(Duration::ZERO, old.parse);
+        assert!(!old.contended);
+        assert_eq!(Duration::from_micros(25), old.median);
+        assert_eq!(None, Record::from_line(HEADER));
+        assert_eq!(None, Record::from_line("1,abc,2015"));
+    }
+
+    #[test]
+    fn previous_run_baseline() {
+        let records = vec![
+            rec(1, "aaa", 1, 100),
+            rec(2, "bbb", 1, 120),
+            rec(2, "bbb", 2, 50),
+            rec(3, "ccc", 1, 90),
+        ];
+        let cmp = compare(&records, None);
+        assert_eq!(1, cmp.len());
+        assert_eq!("bbb", cmp[0].baseline.commit);
+        assert_eq!("ccc", cmp[0].current.commit);
+        assert!((cmp[0].change_pcnt() + 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn commit_baseline() {
+        let records = vec![
+            rec(1, "aaa1111", 1, 100),
+            rec(2, "bbb2222", 1, 120),
+            rec(3, "ccc3333", 1, 150),
+            rec(3, "ccc3333", 2, 10),
+        ];
+        let cmp = compare(&records, Some("aaa"));
+        assert_eq!(1, cmp.len());
+        assert_eq!("aaa1111", cmp[0].baseline.commit);
+        assert!((cmp[0].change_pcnt() - 50.0).abs() < 1e-9);
+    }
+}
@@ -1,13 +1,30 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+mod answers;
 mod bench;
+mod cli;
 mod days;
+mod environment;
+mod examples;
+mod history;
+mod memory;
+mod report;
+mod revisions;
+mod scaffold;
 
-use bench::bench;
-use days::Year;
+use answers::Check;
+use aoc_solution::Answers;
+use bench::{bench, BenchResult, Failure};
+use cli::{Command, CompareOptions, RunOptions, Selection};
+use days::{Day, Year};
+use memory::{Bytes, Usage};
+use report::Format;
 use std::{
-    io::{self, BufRead, Write},
-    time::Duration,
+    collections::HashMap,
+    hint::black_box,
+    io::{self, BufRead, IsTerminal, Write},
+    process::ExitCode,
+    time::{Duration, Instant},
 };
 use took::Took;
 
@@ -20,15 +37,90 @@ const ANSI_RESET: &str = "\x1b[0m";
 const ANSI_ERASE_IN_LINE: &str = "\x1b[2K";
 const ANSI_PREVIOUS_LINE: &str = "\x1b[F";
 
-fn main() -> io::Result<()> {
+/// Footnote for the days marked with a `*`
+const CONTENDED: &str =
+    "* Timed while a day that ran out of time was still going in the background, so likely too slow";
+
+fn main() -> io::Result<ExitCode> {
+    match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Interactive) => interactive()?,
+        Ok(Command::Help) => cli::usage(),
+        Ok(Command::Run(opts)) => {
+            if let Some(cpu) = opts.pin {
+                if let Err(e) = bench::pin_to_cpu(cpu) {
+                    eprintln!("Couldn't pin the runner to CPU {cpu}: {e}");
+                    return Ok(ExitCode::FAILURE);
+                }
+            }
+            if let Err(e) = run_selection(&opts) {
+                eprintln!("{e}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Ok(Command::Compare(opts)) => {
+            if !compare_runs(&opts)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Ok(Command::CompareRev {
+            year,
+            day,
+            base,
+            new,
+        }) => {
+            if let Err(e) = compare_revs(year, day, &base, &new) {
+                eprintln!("{e}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Ok(Command::Verify {
+            selection,
+            record,
+            jobs,
+            timeout,
+        }) => {
+            if !verify(&selection, record, jobs, timeout)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Ok(Command::Examples(selection)) => match check_examples(&selection) {
+            Ok(true) => {}
+            Ok(false) => return Ok(ExitCode::FAILURE),
+            Err(e) => {
+                eprintln!("{e}");
+                return Ok(ExitCode::FAILURE);
+            }
+        },
+        Ok(Command::New { year, day }) => {
+            if let Err(e) = scaffold::new_day(year, day) {
+                eprintln!("{e}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("Run 'aoc-rs help' to see the options");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn interactive() -> io::Result<()> {
     welcome();
 
     show_years();
-    let year = pick_year()?;
-    if year.is_none() {
-        return Ok(());
-    }
-    let year = year.unwrap();
+    let year = match pick_year()? {
+        None => return Ok(()),
+        Some(YearChoice::All) => {
+            let selection = Selection {
+                years: days::YEARS.to_vec(),
+                days: None,
+            };
+            return run_selection(&RunOptions::new(selection));
+        }
+        Some(YearChoice::One(year)) => year,
+    };
 
     let day = pick_day(year)?;
     if day.is_none() {
@@ -37,55 +129,743 @@ fn main() -> io::Result<()> {
     let day = day.unwrap();
     clear_days_menu(year)?;
 
-    if day == 0 {
-        run_all_days(year)?;
-    } else {
-        let (txt, d) = run_day(year, day);
-        Took::from_std(d).describe(txt);
+    let selection = Selection {
+        years: vec![year],
+        days: (day != 0).then(|| vec![year.days()[day - 1].number]),
+    };
+    run_selection(&RunOptions::new(selection))
+}
+
+fn run_selection(opts: &RunOptions) -> io::Result<()> {
+    let selection = &opts.selection;
+    if opts.format != Format::Table {
+        return report_selection(opts);
+    }
+    println!("Run on {}", environment::Environment::detect());
+    if let Some((year, day)) = selection.single() {
+        let day = days::get(year, day).expect("single days are checked when parsing");
+        let day = with_input(day, opts)?;
+        let txt = format!("{year} {}", day.name);
+        let benched = bench_day(day, opts, |_, _| {});
+        match &benched.result {
+            Ok(res) => {
+                describe_result(&txt, res);
+                if let Some(usage) = benched.memory {
+                    describe_memory(usage);
+                }
+                // The answers aren't known for someone else's input, so they're worth showing
+                if opts.input.is_some() {
+                    if let Ok(answers) = bench::isolate(move || day.run(), opts.timeout) {
+                        for part in [1, 2] {
+                            // Screens that couldn't be read start on a line of their own
+                            match answers.part(part) {
+                                answer if answer.contains('\n') => {
+                                    println!("  part {part}:\n{answer}");
+                                }
+                                answer => println!("  part {part}: {answer}"),
+                            }
+                        }
+                    }
+                }
+                if opts.input.is_none() {
+                    history::record(year, &[(day.number, *res)])?;
+                }
+            }
+            Err(e) => println!("{txt} {e}"),
+        }
+        return Ok(());
     }
 
+    let mut all = Vec::new();
+    for &year in &selection.years {
+        let days = selection.days_for(year);
+        if days.is_empty() {
+            println!("{year}: no days to run");
+            println!();
+            continue;
+        }
+        println!("{year}");
+        all.push((year, run_days(year, &days, opts)?));
+        println!();
+    }
+    if all.len() > 1 {
+        summarise_years(&all, opts.slowest);
+    }
     Ok(())
 }
 
-fn run_all_days(year: Year) -> io::Result<()> {
+/// Run the selected days, printing them all in one go in a machine readable format
+///
+/// Each day gets one extra run to check its answers against the ones on record
+fn report_selection(opts: &RunOptions) -> io::Result<()> {
+    let selection = &opts.selection;
+    let mut store = answers::Store::load()?;
+    let mut rows = Vec::new();
+    for &year in &selection.years {
+        let days = selection
+            .days_for(year)
+            .into_iter()
+            .map(|day| with_input(day, opts))
+            .collect::<io::Result<Vec<_>>>()?;
+        for benched in bench_days(year, &days, opts)? {
+            let day = benched.day;
+            // No point trying to get answers out of a day that didn't finish the first time
+            let actual = benched
+                .result
+                .as_ref()
+                .ok()
+                .and_then(|_| bench::isolate(move || day.run(), opts.timeout).ok());
+            // The answers on record are only for the day's own input
+            let checks = actual.map(|actual| match opts.input {
+                Some(_) => [Check::Missing; 2],
+                None => [1, 2].map(|part| store.check(year, day.number, part, &actual, false)),
+            });
+            rows.push(report::Row {
+                year,
+                day: day.number,
+                title: day.title,
+                result: benched.result,
+                memory: benched.memory,
+                checks,
+            });
+        }
+    }
+    let env = environment::Environment::detect();
+    print!("{}", report::render(opts.format, &env, &rows));
+    Ok(())
+}
+
+/// Swap in the input file given on the command line, if there was one
+fn with_input(day: Day, opts: &RunOptions) -> io::Result<Day> {
+    let Some(path) = &opts.input else {
+        return Ok(day);
+    };
+    let input = days::read_input(path)?;
+    Ok(Day { input, ..day })
+}
+
+/// How a day got on when it was benchmarked
+struct Benched {
+    day: Day,
+    result: Result<BenchResult, Failure>,
+    /// Only profiled when asked for, & if the day finished
+    memory: Option<Usage>,
+}
+
+impl Benched {
+    fn contended(&self) -> bool {
+        self.result.as_ref().is_ok_and(|r| r.contended)
+    }
+}
+
+/// Benchmark the day, then profile its memory use on a run of its own if asked to, so the
+/// counting doesn't slow down the timed runs
+///
+/// The counting can't tell one thread from another, so the memory isn't profiled at all while
+/// an earlier day that timed out is still running, as its allocations would be counted too
+fn bench_day(day: Day, opts: &RunOptions, progress: impl FnMut(usize, Duration)) -> Benched {
+    let result = bench(day, opts.bench, opts.timeout, progress);
+    let memory = (opts.memory && result.is_ok() && bench::strays() == 0)
+        .then(|| {
+            // Counting starts on the day's own thread, so starting the thread isn't included
+            let profiled = move || {
+                memory::start();
+                black_box(day.run());
+                memory::stop()
+            };
+            let usage = bench::isolate(profiled, opts.timeout);
+            // Otherwise a day that's still going would leave the counting switched on, & the
+            // next day's memory is left blank as it'd be counted in with this one's
+            memory::stop();
+            usage.ok()
+        })
+        .flatten();
+    Benched {
+        day,
+        result,
+        memory,
+    }
+}
+
+/// Benchmark each of the days & add the ones that finished to the history
+fn bench_days(year: Year, days: &[Day], opts: &RunOptions) -> io::Result<Vec<Benched>> {
+    // Progress updates only make sense when a person is watching. They go to stderr, as stdout
+    // is silenced while the days are running
+    let show_progress = io::stderr().is_terminal();
+    let previous = if show_progress {
+        history::latest()?
+    } else {
+        HashMap::new()
+    };
+    // Going by how long each run took last time. Days that haven't been run before are guessed at
+    // the target time, which is about how long any quick day takes
+    let expected = days
+        .iter()
+        .map(|day| {
+            previous
+                .get(&(year.number(), day.number))
+                .map_or(opts.bench.target, |rec| {
+                    let memory_run = if opts.memory {
+                        rec.mean
+                    } else {
+                        Duration::ZERO
+                    };
+                    bench::expected_time(opts.bench, rec.mean) + memory_run
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let started = Instant::now();
     let mut results = Vec::new();
-    for day in 1..=days::count(year) {
-        let (_, day_txt) = days::get(year, day);
-        let output = format!("Calculating {day_txt}...");
-        replace_current_line(&output)?;
-        results.push(run_day(year, day));
-    }
-    replace_current_line("")?;
-
-    let total = results.iter().map(|&(_, d)| d).sum();
-    // Calculate percentages
-    println!("|{:=>7}==={:=>10}==={:=>5}=|", "", "", "");
-    println!("|{:^7} | {:^10} | {:^5} |", "Day", "Time", "%");
-    println!("|{:=>7}=+={:=>10}=+={:=>5}=|", "", "", "");
-    for &(txt, d) in &results {
-        let pcnt = div_duration_pcnt(d, total);
-        let formatted = Took::from_std(d);
-        println!("|{txt:>7} | {formatted:>10} | {pcnt:>4}% |");
-        println!("|{:->7}-+-{:->10}-+-{:->4}--|", "", "", "");
+    for (i, &day) in days.iter().enumerate() {
+        let progress = |runs: usize, so_far: Duration| {
+            if !show_progress {
+                return;
+            }
+            let left = expected[i].saturating_sub(so_far) + expected[i + 1..].iter().sum();
+            let line = format!(
+                "Calculating {} ({}/{}), run {runs}, {} so far, about {} left",
+                day.name,
+                i + 1,
+                days.len(),
+                Took::from_std(started.elapsed()),
+                Took::from_std(left)
+            );
+            // Nothing is lost if the progress line can't be shown
+            let _ = replace_progress_line(&line);
+        };
+        progress(0, Duration::ZERO);
+        results.push(bench_day(day, opts, progress));
     }
+    if show_progress {
+        replace_progress_line("")?;
+    }
+    // Timings on someone else's input can't be compared to the rest of the history
+    if opts.input.is_none() {
+        let history = results
+            .iter()
+            .filter_map(|b| Some((b.day.number, *b.result.as_ref().ok()?)))
+            .collect::<Vec<_>>();
+        history::record(year, &history)?;
+    }
+    Ok(results)
+}
+
+/// Benchmark the days & print them as a table, returning the ones that finished
+fn run_days(year: Year, days: &[Day], opts: &RunOptions) -> io::Result<Vec<(Day, BenchResult)>> {
+    let results = bench_days(year, days, opts)?;
 
+    // Median is used for the totals as it's not dragged about by the odd slow run
+    let total = results
+        .iter()
+        .filter_map(|b| b.result.as_ref().ok())
+        .map(|r| r.median)
+        .sum();
+    // The memory columns are tacked on the end, only if they were asked for
+    let mem = opts.memory;
+    print!(
+        "|{:=>7}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>10}==={:=>4}==={:=>5}=|",
+        "", "", "", "", "", "", "", "", "", "", "", ""
+    );
+    if mem {
+        print!("={:=>10}==={:=>10}==={:=>10}=|", "", "", "");
+    }
     println!();
+    print!(
+        "|{:^7} | {:^10} | {:^10} | {:^10} | {:^10} | {:^10} | {:^10} | {:^10} | {:^10} | {:^10} | {:^4} | {:^5} |",
+        "Day", "Parse", "Part 1", "Part 2", "Min", "Median", "Mean", "Std dev", "p95", "Max", "Runs", "%"
+    );
+    if mem {
+        print!(
+            " {:^10} | {:^10} | {:^10} |",
+            "Peak mem", "Allocs", "Allocated"
+        );
+    }
+    println!();
+    print!(
+        "|{:=>7}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>10}=+={:=>4}=+={:=>5}=|",
+        "", "", "", "", "", "", "", "", "", "", "", ""
+    );
+    if mem {
+        print!("={:=>10}=+={:=>10}=+={:=>10}=|", "", "", "");
+    }
+    println!();
+    for Benched {
+        day,
+        result,
+        memory,
+    } in &results
+    {
+        let mark = if result.as_ref().is_ok_and(|r| r.contended) {
+            "*"
+        } else {
+            ""
+        };
+        let txt = format!("{}{mark}", day.name);
+        match result {
+            Ok(res) => {
+                let pcnt = div_duration_pcnt(res.median, total);
+                print!(
+                    "|{txt:>7} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>4} | {pcnt:>4}% |",
+                    Took::from_std(res.phases.parse),
+                    Took::from_std(res.phases.part_one),
+                    Took::from_std(res.phases.part_two),
+                    Took::from_std(res.min),
+                    Took::from_std(res.median),
+                    Took::from_std(res.mean),
+                    Took::from_std(res.std_dev),
+                    Took::from_std(res.p95),
+                    Took::from_std(res.max),
+                    res.samples,
+                );
+            }
+            Err(e) => print!(
+                "|{txt:>7} | {:^10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>4} | {:>5} |",
+                e.label(), "", "", "", "", "", "", "", "", "", ""
+            ),
+        }
+        match memory {
+            Some(usage) if mem => print!(
+                " {:>10} | {:>10} | {:>10} |",
+                Bytes(usage.peak),
+                usage.allocations,
+                Bytes(usage.allocated)
+            ),
+            _ if mem => print!(" {:>10} | {:>10} | {:>10} |", "", "", ""),
+            _ => (),
+        }
+        println!();
+        print!(
+            "|{:->7}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->10}-+-{:->4}-+-{:->4}--|",
+            "", "", "", "", "", "", "", "", "", "", "", ""
+        );
+        if mem {
+            print!("-{:->10}-+-{:->10}-+-{:->10}-|", "", "", "");
+        }
+        println!();
+    }
+
+    println!();
+    print_notes(&results);
     Took::from_std(total).describe("All days");
 
+    Ok(results
+        .into_iter()
+        .filter_map(|b| Some((b.day, b.result.ok()?)))
+        .collect())
+}
+
+/// Why any days didn't finish, & what the `*` on the days that might be too slow means
+fn print_notes(results: &[Benched]) {
+    for b in results {
+        if let Err(e) = &b.result {
+            println!("{} {e}", b.day.name);
+        }
+    }
+    if results.iter().any(Benched::contended) {
+        println!("{CONTENDED}");
+    }
+}
+
+/// Subtotals for each year, followed by the slowest days across all of them & the grand total
+fn summarise_years(results: &[(Year, Vec<(Day, BenchResult)>)], slowest: usize) {
+    let subtotal = |days: &[(Day, BenchResult)]| days.iter().map(|(_, r)| r.median).sum();
+    let total = results.iter().map(|(_, days)| subtotal(days)).sum();
+
+    println!("|{:=>6}==={:=>10}==={:=>4}==={:=>5}=|", "", "", "", "");
+    println!(
+        "|{:^6} | {:^10} | {:^4} | {:^5} |",
+        "Year", "Total", "Days", "%"
+    );
+    println!("|{:=>6}=+={:=>10}=+={:=>4}=+={:=>5}=|", "", "", "", "");
+    for (year, days) in results {
+        let sub = subtotal(days);
+        let pcnt = div_duration_pcnt(sub, total);
+        println!(
+            "|{:>6} | {:>10} | {:>4} | {pcnt:>4}% |",
+            year.number(),
+            Took::from_std(sub),
+            days.len()
+        );
+        println!("|{:->6}-+-{:->10}-+-{:->4}-+-{:->4}--|", "", "", "", "");
+    }
+    println!();
+
+    let mut days = results
+        .iter()
+        .flat_map(|(year, days)| days.iter().map(move |(day, res)| (*year, day.name, res)))
+        .collect::<Vec<_>>();
+    days.sort_by_key(|&(_, _, res)| std::cmp::Reverse(res.median));
+    if slowest > 0 {
+        println!("Slowest {} days:", slowest.min(days.len()));
+        for (year, txt, res) in days.iter().take(slowest) {
+            let pcnt = div_duration_pcnt(res.median, total);
+            let mark = if res.contended { "*" } else { " " };
+            println!(
+                "  {year} {txt:>7}{mark} {:>10} {pcnt:>4}%",
+                Took::from_std(res.median)
+            );
+        }
+        println!();
+    }
+    if days.iter().any(|(_, _, res)| res.contended) {
+        println!("{CONTENDED}");
+    }
+
+    // This is the one number to keep an eye on for the repo as a whole
+    Took::from_std(total).describe("All years");
+}
+
+fn describe_result(txt: &str, res: &BenchResult) {
+    Took::from_std(res.median).describe(txt);
+    println!(
+        "  min {}, max {}, mean {} ± {}, p95 {}",
+        Took::from_std(res.min),
+        Took::from_std(res.max),
+        Took::from_std(res.mean),
+        Took::from_std(res.std_dev),
+        Took::from_std(res.p95),
+    );
+    println!(
+        "  parse {}, part 1 {}, part 2 {}",
+        Took::from_std(res.phases.parse),
+        Took::from_std(res.phases.part_one),
+        Took::from_std(res.phases.part_two),
+    );
+    match res.outliers {
+        0 => println!("  {} runs", res.samples),
+        n => println!("  {} runs, after dropping {n} outliers", res.samples),
+    }
+}
+
+fn describe_memory(usage: Usage) {
+    println!(
+        "  memory peak {}, {} allocations, {} allocated in total",
+        Bytes(usage.peak),
+        usage.allocations,
+        Bytes(usage.allocated)
+    );
+}
+
+/// Run each selected day once & check its answers against the ones on record, returning false
+/// if any of them are wrong
+///
+/// With more than one job the days are run side by side, which is quicker but makes the times
+/// less reliable
+fn verify(selection: &Selection, record: bool, jobs: usize, timeout: Duration) -> io::Result<bool> {
+    let days = selection
+        .years
+        .iter()
+        .flat_map(|&year| selection.days_for(year).into_iter().map(move |d| (year, d)))
+        .collect::<Vec<_>>();
+    let solved = if jobs > 1 {
+        let just_days = days.iter().map(|&(_, d)| d).collect::<Vec<_>>();
+        bench::solve_all(&just_days, jobs, timeout)
+    } else {
+        solve_in_turn(&days, timeout)?
+    };
+
+    let mut store = answers::Store::load()?;
+    let mut results = Vec::new();
+    for (&(year, day), solved) in days.iter().zip(solved) {
+        let checks = solved.as_ref().ok().map(|(actual, _)| {
+            [1, 2].map(|part| store.check(year, day.number, part, actual, record))
+        });
+        results.push((year, day, solved, checks));
+    }
+    if record {
+        store.save()?;
+    }
+
+    println!(
+        "|{:=>6}==={:=>7}==={:=>10}==={:=>6}==={:=>6}=|",
+        "", "", "", "", ""
+    );
+    println!(
+        "|{:^6} | {:^7} | {:^10} | {:^6} | {:^6} |",
+        "Year", "Day", "Time", "Part 1", "Part 2"
+    );
+    println!(
+        "|{:=>6}=+={:=>7}=+={:=>10}=+={:=>6}=+={:=>6}=|",
+        "", "", "", "", ""
+    );
+    for (year, day, solved, checks) in &results {
+        let txt = day.name;
+        let took = match solved {
+            Ok((_, took)) => Took::from_std(*took).to_string(),
+            Err(e) => e.label().to_owned(),
+        };
+        let [one, two] = checks.map_or(["", ""], |c| c.map(Check::label));
+        println!(
+            "|{:>6} | {txt:>7} | {took:>10} | {one:^6} | {two:^6} |",
+            year.number()
+        );
+    }
+    println!(
+        "|{:->6}-+-{:->7}-+-{:->10}-+-{:->6}-+-{:->6}-|",
+        "", "", "", "", ""
+    );
+    println!();
+
+    let mut failures = 0;
+    for (year, day, solved, checks) in &results {
+        let txt = day.name;
+        let actual = match solved {
+            Ok((actual, _)) => actual,
+            Err(e) => {
+                failures += 1;
+                println!("{year} {txt} {e}");
+                continue;
+            }
+        };
+        for (part, check) in (1..).zip(checks.iter().flatten()) {
+            if *check != Check::Fail {
+                continue;
+            }
+            failures += 1;
+            let expected = store
+                .expected(*year, day.number, part)
+                .map_or("", String::as_str);
+            let actual = actual.part(part);
+            println!("{year} {txt} part {part}: expected {expected}, got {actual}");
+        }
+    }
+
+    let count = |c: Check| {
+        results
+            .iter()
+            .filter_map(|r| r.3)
+            .flatten()
+            .filter(|&x| x == c)
+            .count()
+    };
+    println!(
+        "{} passed, {failures} failed, {} new, {} with nothing on record",
+        count(Check::Pass),
+        count(Check::Recorded),
+        count(Check::Missing)
+    );
+    Ok(failures == 0)
+}
+
+/// Solve the days one after the other, timing each of them once
+fn solve_in_turn(
+    days: &[(Year, Day)],
+    timeout: Duration,
+) -> io::Result<Vec<Result<(Answers, Duration), Failure>>> {
+    let show_progress = io::stdout().is_terminal();
+    let mut solved = Vec::new();
+    for &(year, day) in days {
+        if show_progress {
+            replace_current_line(&format!("Checking {year} {}...", day.name))?;
+        }
+        let timed = move || {
+            let timer = Instant::now();
+            let actual = day.run();
+            (actual, timer.elapsed())
+        };
+        solved.push(bench::isolate(timed, timeout));
+    }
+    if show_progress {
+        replace_current_line("")?;
+    }
+    Ok(solved)
+}
+
+/// Benchmark a day at both revisions & show how much the change in its mean time can be trusted
+fn compare_revs(year: Year, day: usize, base: &str, new: &str) -> io::Result<()> {
+    let mut timed = Vec::new();
+    for rev in [base, new] {
+        eprintln!("Benchmarking {year} Day {day} at {rev}...");
+        timed.push(revisions::bench_at(rev, year, day)?);
+    }
+    let [base, new] = [&timed[0], &timed[1]];
+
+    println!();
+    println!("{year} Day {day}");
+    for t in [base, new] {
+        println!(
+            "  {:>10}  mean {:>10} ± {:>10}, median {:>10}, {} runs",
+            t.commit,
+            Took::from_std(t.mean).to_string(),
+            Took::from_std(t.std_dev).to_string(),
+            Took::from_std(t.median).to_string(),
+            t.samples
+        );
+    }
+    let change = revisions::change(base, new);
+    let verdict = if !change.significant() {
+        "no clear difference"
+    } else if change.pcnt < 0.0 {
+        "faster"
+    } else {
+        "slower"
+    };
+    println!(
+        "  {} is {:+.1}% ({:+.1}% to {:+.1}% at 95% confidence), {verdict}",
+        new.commit, change.pcnt, change.low, change.high
+    );
     Ok(())
 }
 
+/// Run every day's examples, then print a matrix of which days passed in each year, followed by
+/// any cases that went wrong
+fn check_examples(selection: &Selection) -> io::Result<bool> {
+    let show_progress = io::stdout().is_terminal();
+    let mut years = Vec::new();
+    for &year in &selection.years {
+        let mut results = Vec::new();
+        for day in selection.days_for(year) {
+            if show_progress {
+                replace_current_line(&format!("Checking {year} {} examples...", day.name))?;
+            }
+            let (status, checked) = examples::check_day(year, day, cli::TIMEOUT)?;
+            results.push((day, status, checked));
+        }
+        if !results.is_empty() {
+            years.push((year, results));
+        }
+    }
+    if show_progress {
+        replace_current_line("")?;
+    }
+
+    let last = years
+        .iter()
+        .flat_map(|(_, results)| results.iter().map(|(day, ..)| day.number))
+        .max()
+        .unwrap_or(0);
+    print!("Year |");
+    for day in 1..=last {
+        print!("{day:>3}");
+    }
+    println!();
+    println!("-----+{:-<width$}", "", width = 3 * last);
+    for (year, results) in &years {
+        print!("{year} |");
+        for day in 1..=last {
+            let label = results
+                .iter()
+                .find(|(d, ..)| d.number == day)
+                .map_or("", |(_, status, _)| status.label());
+            print!("{label:>3}");
+        }
+        println!();
+    }
+    println!();
+    println!(
+        "✓ passed, ✗ failed, ? no answers for the examples in src/examples.txt, · no examples"
+    );
+    println!();
+
+    let mut passed = 0;
+    let mut failed = 0;
+    for (year, results) in &years {
+        for (day, _, checked) in results {
+            for check in checked {
+                if check.passed() {
+                    passed += 1;
+                    continue;
+                }
+                failed += 1;
+                let case = &check.case;
+                let txt = format!("{year} {} {} part {}", day.name, case.file, case.part);
+                match &check.actual {
+                    Ok(actual) => println!("{txt}: expected {}, got {actual}", case.expected),
+                    Err(e) => println!("{txt} {e}"),
+                }
+            }
+        }
+    }
+    let unchecked = years
+        .iter()
+        .flat_map(|(_, results)| results)
+        .filter(|(_, status, _)| *status == examples::Status::Unchecked)
+        .count();
+    println!("{passed} passed, {failed} failed, unchecked days: {unchecked}");
+    Ok(failed == 0)
+}
+
+/// Print how each day has changed against its baseline, returning false if any got too slow
+fn compare_runs(opts: &CompareOptions) -> io::Result<bool> {
+    let records = history::load()?;
+    let comparisons = history::compare(&records, opts.baseline.as_deref());
+    if comparisons.is_empty() {
+        match &opts.baseline {
+            Some(commit) => println!("No runs found to compare against commit {commit}"),
+            None => println!("Need at least two runs of a day to compare them"),
+        }
+        return Ok(true);
+    }
+
+    println!(
+        "|{:=>6}==={:=>5}==={:=>18}==={:=>18}==={:=>8}==={:=>6}=|",
+        "", "", "", "", "", ""
+    );
+    println!(
+        "|{:^6} | {:^5} | {:^18} | {:^18} | {:^8} | {:^6} |",
+        "Year", "Day", "Baseline", "Current", "Change", ""
+    );
+    println!(
+        "|{:=>6}=+={:=>5}=+={:=>18}=+={:=>18}=+={:=>8}=+={:=>6}=|",
+        "", "", "", "", "", ""
+    );
+    let mut regressions = 0;
+    for c in &comparisons {
+        let change = c.change_pcnt();
+        let flag = if change > opts.threshold {
+            regressions += 1;
+            "SLOWER"
+        } else {
+            ""
+        };
+        let base = format!(
+            "{:>10} {:>7}",
+            Took::from_std(c.baseline.median),
+            c.baseline.commit
+        );
+        let curr = format!(
+            "{:>10} {:>7}",
+            Took::from_std(c.current.median),
+            c.current.commit
+        );
+        println!(
+            "|{:>6} | {:>5} | {base:>18} | {curr:>18} | {change:>+7.1}% | {flag:>6} |",
+            c.current.year, c.current.day
+        );
+    }
+    println!(
+        "|{:->6}-+-{:->5}-+-{:->18}-+-{:->18}-+-{:->8}-+-{:->6}-|",
+        "", "", "", "", "", ""
+    );
+    println!();
+
+    if regressions == 0 {
+        println!("No days are more than {}% slower", opts.threshold);
+    } else {
+        println!(
+            "{regressions} day(s) are more than {}% slower",
+            opts.threshold
+        );
+    }
+    Ok(regressions == 0)
+}
+
+/// A share of a total as a percentage, which is 0 when nothing succeeded & the total is empty
 const fn div_duration_pcnt(numerator: Duration, denominator: Duration) -> u128 {
-    100 * numerator.as_nanos() / denominator.as_nanos()
+    match denominator.as_nanos() {
+        0 => 0,
+        denominator => 100 * numerator.as_nanos() / denominator,
+    }
 }
 
-fn run_day(year: Year, day: usize) -> (&'static str, Duration) {
-    let (f, txt) = days::get(year, day);
-    let res = bench(f);
-    (txt, res)
+/// A year picked from the menu, or all of them
+enum YearChoice {
+    All,
+    One(Year),
 }
 
-fn pick_year() -> io::Result<Option<Year>> {
+fn pick_year() -> io::Result<Option<YearChoice>> {
     let mut buffer = String::new();
 
     loop {
@@ -94,64 +874,15 @@ fn pick_year() -> io::Result<Option<Year>> {
         trim_newline(&mut buffer);
 
         match buffer.as_str() {
-            "1" | "2015" => {
-                if confirm_year("2015")? {
-                    return Ok(Some(Year::Y2015));
-                }
-            }
-            "2" | "2016" => {
-                if confirm_year("2016")? {
-                    return Ok(Some(Year::Y2016));
-                }
-            }
-            "3" | "2017" => {
-                if confirm_year("2017")? {
-                    return Ok(Some(Year::Y2017));
-                }
-            }
-            "4" | "2018" => {
-                if confirm_year("2018")? {
-                    return Ok(Some(Year::Y2018));
-                }
-            }
-            "5" | "2019" => {
-                if confirm_year("2019")? {
-                    return Ok(Some(Year::Y2019));
-                }
-            }
-            "6" | "2020" => {
-                if confirm_year("2020")? {
-                    return Ok(Some(Year::Y2020));
-                }
-            }
-            "7" | "2021" => {
-                if confirm_year("2021")? {
-                    return Ok(Some(Year::Y2021));
-                }
-            }
-            "8" | "2022" => {
-                if confirm_year("2022")? {
-                    return Ok(Some(Year::Y2022));
-                }
-            }
-            "9" | "2023" => {
-                if confirm_year("2023")? {
-                    return Ok(Some(Year::Y2023));
-                }
-            }
-            "10" | "2024" => {
-                if confirm_year("2024")? {
-                    return Ok(Some(Year::Y2024));
-                }
-            }
-            "11" | "2025" | "" => {
-                if confirm_year("2025")? {
-                    return Ok(Some(Year::Y2025));
-                }
-            }
             "h" | "help" => show_years(),
             "q" | "quit" | "exit" => return Ok(None),
-            _ => {
+            sel => {
+                if let Some(choice) = year_choice(sel) {
+                    if confirm_year(&choice)? {
+                        return Ok(Some(choice));
+                    }
+                    continue;
+                }
                 println!("Bad option: You typed '{buffer}'");
                 show_short_options();
             }
@@ -159,9 +890,29 @@ fn pick_year() -> io::Result<Option<Year>> {
     }
 }
 
-fn confirm_year(year: &str) -> io::Result<bool> {
+/// The year picked from the menu, either by its list number or the whole year. Blank picks the
+/// most recent year & 0 picks all of them
+fn year_choice(sel: &str) -> Option<YearChoice> {
+    if sel.is_empty() {
+        return days::YEARS.last().copied().map(YearChoice::One);
+    }
+    let num = sel.parse::<u16>().ok()?;
+    if num == 0 {
+        return Some(YearChoice::All);
+    }
+    let idx = usize::from(num);
+    if (1..=days::YEARS.len()).contains(&idx) {
+        return Some(YearChoice::One(days::YEARS[idx - 1]));
+    }
+    Year::try_from(num).ok().map(YearChoice::One)
+}
+
+fn confirm_year(choice: &YearChoice) -> io::Result<bool> {
     let mut handle = io::stdout().lock();
-    write!(handle, "Running {year}. Please confirm [y]/n  ")?;
+    match choice {
+        YearChoice::All => write!(handle, "Running all years. Please confirm [y]/n  ")?,
+        YearChoice::One(year) => write!(handle, "Running {year}. Please confirm [y]/n  ")?,
+    }
     handle.flush()?;
 
     let mut buffer = String::new();
@@ -218,7 +969,7 @@ fn confirm_day(year: Year, day: usize) -> io::Result<bool> {
     if day == 0 {
         write!(handle, "Running all days in {year}. Please confirm [y]/n  ")?;
     } else {
-        let (_, day_str) = days::get(year, day);
+        let day_str = year.days()[day - 1].name;
         write!(handle, "Running {year}, {day_str}. Please confirm [y]/n  ")?;
     }
     handle.flush()?;
@@ -241,30 +992,15 @@ fn show_days(year: Year) {
     println!();
     println!("Choose a day to run from {year}:");
     println!(" 0: All Days (default)");
-    match year {
-        Year::Y2015 => show_days_inner(days::DAYS_2015.iter()),
-        Year::Y2016 => show_days_inner(days::DAYS_2016.iter()),
-        Year::Y2017 => show_days_inner(days::DAYS_2017.iter()),
-        Year::Y2018 => show_days_inner(days::DAYS_2018.iter()),
-        Year::Y2019 => show_days_inner(days::DAYS_2019.iter()),
-        Year::Y2020 => show_days_inner(days::DAYS_2020.iter()),
-        Year::Y2021 => show_days_inner(days::DAYS_2021.iter()),
-        Year::Y2022 => show_days_inner(days::DAYS_2022.iter()),
-        Year::Y2023 => show_days_inner(days::DAYS_2023.iter()),
-        Year::Y2024 => show_days_inner(days::DAYS_2024.iter()),
-        Year::Y2025 => show_days_inner(days::DAYS_2025.iter()),
-    };
+    show_days_inner(year.days());
     println!();
     println!("Type the list number, but NOT the day value");
     println!("  - blank will select all days");
     show_short_options();
 }
 
-fn show_days_inner<'a, I>(days: I)
-where
-    I: Iterator<Item = &'a (fn(), &'a str)>,
-{
-    for ((_, txt), idx) in days.zip(1..) {
+fn show_days_inner(days: &[Day]) {
+    for (Day { name: txt, .. }, idx) in days.iter().zip(1..) {
         if idx > 9 {
             println!("{idx}: {txt}");
         } else {
@@ -281,6 +1017,7 @@ fn clear_days_menu(year: Year) -> io::Result<()> {
 
 fn show_years() {
     println!("Choose a year to run:");
+    println!(" 0:   All Years");
     for (year, idx) in days::YEARS.iter().zip(1..) {
         show_year_inner(idx, *year);
     }
@@ -317,7 +1054,9 @@ fn welcome() {
     println!("================================");
     println!("{ANSI_GREY}https://github.com/jmacadie/aoc-rs{ANSI_RESET}");
     println!();
-    println!("This tool is used to performance profile (run-time only) my solutions.");
+    println!(
+        "This tool is used to performance profile (run-time, & optionally memory) my solutions."
+    );
     println!("All days are written in Rust.");
     println!("Individual days are their own binaries and can be run (to get the answers) by");
     println!(
@@ -340,9 +1079,15 @@ fn clear_previous_lines(num: usize) -> io::Result<()> {
 }
 
 fn replace_current_line(new_line: &str) -> io::Result<()> {
-    let stdout = io::stdout();
-    let mut handle = stdout.lock();
+    replace_line(&mut io::stdout().lock(), new_line)
+}
+
+/// Same as `replace_current_line`, but on stderr so it still shows while stdout is silenced
+fn replace_progress_line(new_line: &str) -> io::Result<()> {
+    replace_line(&mut io::stderr().lock(), new_line)
+}
 
+fn replace_line(handle: &mut impl Write, new_line: &str) -> io::Result<()> {
     write!(handle, "{ANSI_ERASE_IN_LINE}\r")?;
     write!(handle, "{new_line}")?;
     handle.flush()?;
@@ -0,0 +1,150 @@
+#[cfg(feature = "memory")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+/// The system allocator, but keeping count of what gets allocated while profiling is switched on
+///
+/// Counting is off by default, so the timings aren't skewed by it. Even then every allocation has
+/// to check whether it's on, so it's only installed with the `memory` feature
+#[cfg(feature = "memory")]
+struct Counting;
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes currently allocated, relative to when profiling started. This can dip below zero as
+/// memory from before then gets freed
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: all the real work is passed straight on to the system allocator
+#[cfg(feature = "memory")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            grow(layout.size());
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            grow(layout.size());
+        }
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ENABLED.load(Ordering::Relaxed) {
+            CURRENT.fetch_sub(to_isize(layout.size()), Ordering::Relaxed);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            // Counted as a fresh allocation, which is near enough what happens when it has to move
+            CURRENT.fetch_sub(to_isize(layout.size()), Ordering::Relaxed);
+            grow(new_size);
+        }
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[cfg(feature = "memory")]
+fn grow(size: usize) {
+    let now = CURRENT.fetch_add(to_isize(size), Ordering::Relaxed) + to_isize(size);
+    PEAK.fetch_max(now, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+}
+
+#[cfg(feature = "memory")]
+fn to_isize(size: usize) -> isize {
+    isize::try_from(size).unwrap_or(isize::MAX)
+}
+
+/// What got allocated while profiling was switched on
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// Most memory held at any one time
+    pub peak: usize,
+    /// Number of allocations made
+    pub allocations: usize,
+    /// Bytes allocated in total, without taking off anything that was freed
+    pub allocated: usize,
+}
+
+/// Start counting allocations from zero. Everything allocated on any thread gets counted, so
+/// nothing else should be running at the same time
+pub fn start() {
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED.store(0, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Stop counting & get what was allocated since `start`
+pub fn stop() -> Usage {
+    ENABLED.store(false, Ordering::SeqCst);
+    Usage {
+        peak: usize::try_from(PEAK.load(Ordering::Relaxed)).unwrap_or(0),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+    }
+}
+
+/// A number of bytes, shown in the biggest unit that keeps it above 1
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+        #[allow(clippy::cast_precision_loss)]
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        // Padded as a whole, so the bytes line up in tables
+        if unit == 0 {
+            f.pad(&format!("{} {}", self.0, UNITS[0]))
+        } else {
+            f.pad(&format!("{size:.2} {}", UNITS[unit]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!("0 B", Bytes(0).to_string());
+        assert_eq!("1023 B", Bytes(1023).to_string());
+        assert_eq!("1.00 KiB", Bytes(1024).to_string());
+        assert_eq!("1.50 MiB", Bytes(3 * 512 * 1024).to_string());
+        assert_eq!("  1.00 KiB", format!("{:>10}", Bytes(1024)));
+    }
+
+    #[test]
+    #[cfg(feature = "memory")]
+    fn counting() {
+        start();
+        let v = std::hint::black_box(vec![0_u8; 1_000_000]);
+        drop(v);
+        let usage = stop();
+        // Other tests could be allocating at the same time, so this can only be a lower bound
+        assert!(usage.peak >= 1_000_000);
+        assert!(usage.allocated >= 1_000_000);
+        assert!(usage.allocations >= 1);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::answers::Check;
+use crate::bench::{BenchResult, Failure};
+use crate::days::Year;
+use crate::environment::Environment;
+use crate::memory::Usage;
+use std::fmt::Write as _;
+use std::time::Duration;
+use took::Took;
+
+/// How the results of a run get printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The wide table for reading in the terminal
+    Table,
+    Json,
+    Csv,
+    /// The per-year tables from the README
+    Markdown,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The timings & answer checks for one day
+pub struct Row {
+    pub year: Year,
+    pub day: usize,
+    /// The puzzle's title, empty if it isn't known
+    pub title: &'static str,
+    pub result: Result<BenchResult, Failure>,
+    /// Only there if it was profiled
+    pub memory: Option<Usage>,
+    /// Not checked if the day didn't finish
+    pub checks: Option<[Check; 2]>,
+}
+
+/// Render the rows in one of the machine readable formats, the table is printed as the days run
+///
+/// Each format records the environment the days were run in as well
+pub fn render(format: Format, env: &Environment, rows: &[Row]) -> String {
+    match format {
+        Format::Table => unreachable!("the table is printed as the days are run"),
+        Format::Json => json(env, rows),
+        Format::Csv => csv(env, rows),
+        Format::Markdown => markdown(env, rows),
+    }
+}
+
+/// Name for an answer check that's safe to parse, unlike the labels in the table
+const fn status(check: Check) -> &'static str {
+    match check {
+        Check::Pass => "pass",
+        Check::Fail => "fail",
+        Check::Missing => "missing",
+        Check::Recorded => "new",
+    }
+}
+
+/// Whether the day finished, safe to parse like `status`
+const fn outcome(row: &Row) -> &'static str {
+    match &row.result {
+        Ok(_) => "ok",
+        Err(Failure::Panic(_)) => "panic",
+        Err(Failure::Timeout(_)) => "timeout",
+    }
+}
+
+/// The answer checks as strings, which are left empty if the day didn't finish
+fn checks(row: &Row) -> [&'static str; 2] {
+    row.checks.map_or(["", ""], |checks| checks.map(status))
+}
+
+/// The timing & memory fields shared by the json & csv outputs, with durations in nanoseconds
+///
+/// The timings are all missing if the day didn't finish, & the memory if it wasn't profiled
+fn fields(row: &Row) -> [(&'static str, Option<u128>); 14] {
+    let res = row.result.as_ref().ok();
+    let ns = |f: fn(&BenchResult) -> Duration| res.map(|r| f(r).as_nanos());
+    [
+        ("samples", res.map(|r| r.samples as u128)),
+        ("outliers", res.map(|r| r.outliers as u128)),
+        ("min_ns", ns(|r| r.min)),
+        ("median_ns", ns(|r| r.median)),
+        ("mean_ns", ns(|r| r.mean)),
+        ("std_dev_ns", ns(|r| r.std_dev)),
+        ("p95_ns", ns(|r| r.p95)),
+        ("max_ns", ns(|r| r.max)),
+        ("parse_ns", ns(|r| r.phases.parse)),
+        ("part_one_ns", ns(|r| r.phases.part_one)),
+        ("part_two_ns", ns(|r| r.phases.part_two)),
+        ("peak_bytes", row.memory.map(|m| m.peak as u128)),
+        ("allocations", row.memory.map(|m| m.allocations as u128)),
+        ("allocated_bytes", row.memory.map(|m| m.allocated as u128)),
+    ]
+}
+
+/// A json string, with quotes, backslashes & control characters escaped
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json(env: &Environment, rows: &[Row]) -> String {
+    let features = env
+        .target_features
+        .split(',')
+        .filter(|f| !f.is_empty())
+        .map(json_string)
+        .collect::<Vec<_>>();
+    let mut s = String::from("{\n  \"environment\": {");
+    let _ = write!(
+        s,
+        "\"cpu\": {}, \"cores\": {}, \"governor\": {}, \"rustc\": {}, \"profile\": {}, \
+         \"target_features\": [{}], \"commit\": {}, \"dirty\": {}}},\n  \"days\": [",
+        json_string(&env.cpu),
+        env.cores,
+        env.governor
+            .as_deref()
+            .map_or_else(|| String::from("null"), json_string),
+        json_string(env.rustc),
+        json_string(env.profile),
+        features.join(", "),
+        json_string(&env.commit),
+        env.dirty
+    );
+    for (i, row) in rows.iter().enumerate() {
+        s.push_str(if i == 0 { "\n    {" } else { ",\n    {" });
+        let _ = write!(
+            s,
+            "\"year\": {}, \"day\": {}, \"outcome\": \"{}\", ",
+            row.year,
+            row.day,
+            outcome(row)
+        );
+        for (name, value) in fields(row) {
+            match value {
+                Some(v) => {
+                    let _ = write!(s, "\"{name}\": {v}, ");
+                }
+                None => {
+                    let _ = write!(s, "\"{name}\": null, ");
+                }
+            }
+        }
+        match row.checks {
+            Some([one, two]) => {
+                let _ = write!(
+                    s,
+                    "\"part_one\": \"{}\", \"part_two\": \"{}\"}}",
+                    status(one),
+                    status(two)
+                );
+            }
+            None => s.push_str("\"part_one\": null, \"part_two\": null}"),
+        }
+    }
+    s.push_str(if rows.is_empty() {
+        "]\n}\n"
+    } else {
+        "\n  ]\n}\n"
+    });
+    s
+}
+
+/// A csv field, quoted if it has anything in it that would split it up
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// The environment is repeated on every row, so reports from different machines can simply be
+/// joined together
+fn csv(env: &Environment, rows: &[Row]) -> String {
+    let env = env.fields();
+    let mut s = String::new();
+    if let Some(row) = rows.first() {
+        s.push_str("year,day,outcome,");
+        for (name, _) in fields(row) {
+            let _ = write!(s, "{name},");
+        }
+        s.push_str("part_one,part_two");
+        for (name, _) in &env {
+            let _ = write!(s, ",{name}");
+        }
+        s.push('\n');
+    }
+    for row in rows {
+        let _ = write!(s, "{},{},{},", row.year, row.day, outcome(row));
+        for (_, value) in fields(row) {
+            if let Some(v) = value {
+                let _ = write!(s, "{v}");
+            }
+            s.push(',');
+        }
+        let [one, two] = checks(row);
+        let _ = write!(s, "{one},{two}");
+        for (_, value) in &env {
+            let _ = write!(s, ",{}", csv_field(value));
+        }
+        s.push('\n');
+    }
+    s
+}
+
+/// A collapsible table for each year, in the same layout as the Performance section of the README
+fn markdown(env: &Environment, rows: &[Row]) -> String {
+    let mut s = format!("Run on {env}\n\n");
+    let mut rest = rows;
+    while let Some(first) = rest.first() {
+        let len = rest.iter().take_while(|r| r.year == first.year).count();
+        let (year, others) = rest.split_at(len);
+        rest = others;
+
+        // Median is used for the totals as it's not dragged about by the odd slow run
+        let total: Duration = year
+            .iter()
+            .filter_map(|r| r.result.as_ref().ok())
+            .map(|r| r.median)
+            .sum();
+        let links = year
+            .iter()
+            .map(|r| {
+                let text = match r.title {
+                    "" => format!("Day {}", r.day),
+                    title => format!("Day {}: {title}", r.day),
+                };
+                format!("[{text}]({}/day_{:02}/src/main.rs)", r.year, r.day)
+            })
+            .collect::<Vec<_>>();
+        let width = links.iter().map(String::len).max().unwrap_or(0);
+
+        let _ = writeln!(s, "<details>\n  <summary>{}</summary>\n", first.year);
+        let _ = writeln!(s, "  **All Days: {}**\n", Took::from_std(total));
+        s.push_str("  | Day | Runtime | Percentage of year |\n  |---|---|---|\n");
+        for (row, link) in year.iter().zip(&links) {
+            let _ = match &row.result {
+                Ok(res) => {
+                    let pcnt = 100 * res.median.as_nanos() / total.as_nanos().max(1);
+                    writeln!(
+                        s,
+                        "  | {link:<width$} | {:>10} | {pcnt:>3}% |",
+                        Took::from_std(res.median).to_string()
+                    )
+                }
+                Err(e) => writeln!(s, "  | {link:<width$} | {:>10} |      |", e.label()),
+            };
+        }
+        s.push_str("\n</details>\n\n");
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(year: Year, day: usize, median_ms: u64) -> Row {
+        let mut result = BenchResult::from_samples(vec![Duration::from_millis(median_ms)]);
+        result.phases.parse = Duration::from_nanos(5);
+        Row {
+            year,
+            day,
+            title: "",
+            result: Ok(result),
+            memory: None,
+            checks: Some([Check::Pass, Check::Missing]),
+        }
+    }
+
+    fn env() -> Environment {
+        Environment {
+            cpu: String::from("Some \"Fast\" CPU, 4GHz"),
+            cores: 8,
+            governor: None,
+            rustc: "rustc 1.80.0",
+            profile: "release",
+            target_features: "sse,sse2",
+            commit: String::from("abc1234"),
+            dirty: false,
+        }
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Ok(Format::Json), Format::try_from("json"));
+        assert_eq!(Ok(Format::Markdown), Format::try_from("md"));
+        assert!(Format::try_from("yaml").is_err());
+    }
+
+    #[test]
+    fn machine_readable() {
+        let failed = Row {
+            year: Year::Y2016,
+            day: 6,
+            title: "",
+            result: Err(Failure::Timeout(Duration::from_secs(1))),
+            memory: None,
+            checks: None,
+        };
+        let mut rows = [row(Year::Y2015, 1, 3), row(Year::Y2015, 2, 1), failed];
+        rows[0].memory = Some(Usage {
+            peak: 10,
+            allocations: 2,
+            allocated: 20,
+        });
+
+        let csv = csv(&env(), &rows);
+        let mut lines = csv.lines();
+        assert_eq!(
+            Some("year,day,outcome,samples,outliers,min_ns,median_ns,mean_ns,std_dev_ns,p95_ns,max_ns,parse_ns,part_one_ns,part_two_ns,peak_bytes,allocations,allocated_bytes,part_one,part_two,cpu,cores,governor,rustc,profile,target_features,commit,dirty"),
+            lines.next()
+        );
+        let env_fields =
+            ",\"Some \"\"Fast\"\" CPU, 4GHz\",8,,rustc 1.80.0,release,\"sse,sse2\",abc1234,false";
+        assert_eq!(
+            Some(format!("2015,1,ok,1,0,3000000,3000000,3000000,0,3000000,3000000,5,0,0,10,2,20,pass,missing{env_fields}").as_str()),
+            lines.next()
+        );
+        assert_eq!(
+            Some(format!("2016,6,timeout,,,,,,,,,,,,,,,,{env_fields}").as_str()),
+            lines.last()
+        );
+
+        let json = json(&env(), &rows);
+        assert!(json.starts_with(
+            "{\n  \"environment\": {\"cpu\": \"Some \\\"Fast\\\" CPU, 4GHz\", \"cores\": 8, \"governor\": null, \
+             \"rustc\": \"rustc 1.80.0\", \"profile\": \"release\", \"target_features\": [\"sse\", \"sse2\"], \
+             \"commit\": \"abc1234\", \"dirty\": false},\n  \"days\": [\n    {\"year\": 2015, \"day\": 1, \
+             \"outcome\": \"ok\", \"samples\": 1,"
+        ));
+        assert!(json.contains("\"parse_ns\": 5, "));
+        assert!(json.contains("\"part_one\": \"pass\", \"part_two\": \"missing\"},\n    {"));
+        assert!(json.contains("\"outcome\": \"timeout\", \"samples\": null,"));
+        assert!(json.ends_with("\"part_one\": null, \"part_two\": null}\n  ]\n}\n"));
+        assert!(super::json(&env(), &[]).ends_with("\"days\": []\n}\n"));
+        assert_eq!(
+            "\"a\\\"b\\\\c\\nd\\te\\u001b\"",
+            json_string("a\"b\\c\nd\te\u{1b}")
+        );
+    }
+
+    #[test]
+    fn markdown_per_year() {
+        let mut rows = [
+            row(Year::Y2015, 1, 3),
+            row(Year::Y2015, 10, 1),
+            row(Year::Y2016, 1, 1),
+            Row {
+                year: Year::Y2016,
+                day: 2,
+                title: "",
+                result: Err(Failure::Panic(String::new())),
+                memory: None,
+                checks: None,
+            },
+        ];
+        rows[2].title = "No Such Thing as Too Much";
+        let md = markdown(&env(), &rows);
+        assert!(md.starts_with("Run on Some \"Fast\" CPU, 4GHz, 8 cores, rustc 1.80.0, release build, commit abc1234\n\n<details>"));
+        assert_eq!(2, md.matches("<details>").count());
+        assert!(md.contains("  <summary>2016</summary>"));
+        assert!(md.contains("  | [Day 1](2015/day_01/src/main.rs)  |"));
+        assert!(md.contains("  | [Day 10](2015/day_10/src/main.rs) |"));
+        assert!(md.contains("  | [Day 1: No Such Thing as Too Much](2016/day_01/src/main.rs) |"));
+        assert!(md.contains("|  75% |\n"));
+        assert!(md.contains("| 100% |\n"));
+        assert!(md.contains("|      PANIC |      |\n"));
+    }
+}
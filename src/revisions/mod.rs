@@ -0,0 +1,260 @@
+use crate::days::Year;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+/// Both revisions are built into the same target folder, so they share the compiled dependencies
+const TARGET_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/compare-rev");
+/// Two sided 95% point of the standard normal distribution
+const Z_95: f64 = 1.959_964;
+
+/// The timings of a day at one revision, as reported by the runner built from that revision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+    pub commit: String,
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+}
+
+/// The change in a day's mean run time between two revisions, as a percentage of the first
+/// along with its 95% confidence interval. Negative is faster
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub pcnt: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl Change {
+    /// Whether the interval rules out there being no change at all
+    pub fn significant(self) -> bool {
+        self.low > 0.0 || self.high < 0.0
+    }
+}
+
+/// Check out `rev` in a worktree of its own, build the runner there & benchmark the one day
+///
+/// The inputs aren't committed, so they're copied over from this checkout. The worktree is
+/// removed again afterwards, whether or not the benchmark worked
+pub fn bench_at(rev: &str, year: Year, day: usize) -> io::Result<Timed> {
+    let commit = git(&[
+        "rev-parse",
+        "--short",
+        "--verify",
+        &format!("{rev}^{{commit}}"),
+    ])
+    .map_err(|_| io::Error::new(ErrorKind::NotFound, format!("Unknown revision '{rev}'")))?;
+    let tree = std::env::temp_dir().join(format!("aoc-rs-{commit}-{}", std::process::id()));
+    let tree_str = tree.to_string_lossy();
+    git(&["worktree", "add", "--detach", &tree_str, &commit])?;
+
+    let timed = copy_inputs(&tree).and_then(|()| build_and_run(&tree, rev, year, day));
+    let removed = git(&["worktree", "remove", "--force", &tree_str]);
+    let timed = timed?;
+    removed?;
+    Ok(Timed { commit, ..timed })
+}
+
+/// Copy every day's input into the worktree, for the days that exist at that revision
+fn copy_inputs(tree: &Path) -> io::Result<()> {
+    for year in fs::read_dir(ROOT)? {
+        let year = year?;
+        let is_year = year
+            .file_name()
+            .to_str()
+            .is_some_and(|n| n.len() == 4 && n.parse::<u16>().is_ok());
+        if !is_year {
+            continue;
+        }
+        for day in fs::read_dir(year.path())? {
+            let input = day?.path().join("src/input.txt");
+            let Ok(relative) = input.strip_prefix(ROOT) else {
+                continue;
+            };
+            let to = tree.join(relative);
+            if input.exists() && to.parent().is_some_and(Path::exists) {
+                fs::copy(&input, to)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn build_and_run(tree: &Path, rev: &str, year: Year, day: usize) -> io::Result<Timed> {
+    // No point building a whole revision for a day it doesn't have
+    if !tree.join(format!("{year}/day_{day:02}")).is_dir() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!("{year} day {day} doesn't exist at {rev}"),
+        ));
+    }
+
+    // Cargo's own progress is left to show, as building a whole revision takes a while
+    let built = Command::new("cargo")
+        .args(["build", "--release", "--bin", "aoc-rs"])
+        .env("CARGO_TARGET_DIR", TARGET_DIR)
+        .current_dir(tree)
+        .status()?;
+    if !built.success() {
+        return Err(io::Error::other("Couldn't build the runner"));
+    }
+
+    let runner: PathBuf = [TARGET_DIR, "release", "aoc-rs"].iter().collect();
+    let out = Command::new(runner)
+        .args([
+            "run",
+            &year.to_string(),
+            &day.to_string(),
+            "--format",
+            "csv",
+        ])
+        .current_dir(tree)
+        .stderr(Stdio::piped())
+        .output()?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        let reason = match stderr.trim() {
+            "" => out.status.to_string(),
+            reason => reason.to_owned(),
+        };
+        return Err(io::Error::other(format!(
+            "The runner at {rev} couldn't run the day: {reason}"
+        )));
+    }
+    parse_csv(&String::from_utf8_lossy(&out.stdout))
+        .ok_or_else(|| io::Error::other("Couldn't read the timings from the runner"))
+}
+
+/// Pick the stats out of the runner's csv report of a single day, leaving the commit to be filled in
+fn parse_csv(csv: &str) -> Option<Timed> {
+    let mut lines = csv.lines();
+    let header = lines.next()?.split(',').collect::<Vec<_>>();
+    let row = lines.next()?.split(',').collect::<Vec<_>>();
+    let field = |name: &str| {
+        let i = header.iter().position(|&h| h == name)?;
+        row.get(i).copied()
+    };
+    if field("outcome")? != "ok" {
+        return None;
+    }
+    let nanos = |name| field(name)?.parse().ok().map(Duration::from_nanos);
+    Some(Timed {
+        commit: String::new(),
+        samples: field("samples")?.parse().ok()?,
+        mean: nanos("mean_ns")?,
+        median: nanos("median_ns")?,
+        std_dev: nanos("std_dev_ns")?,
+    })
+}
+
+/// Compare the mean run times with Welch's t-test, which doesn't assume both revisions are as
+/// noisy as each other
+#[allow(clippy::cast_precision_loss)]
+pub fn change(base: &Timed, new: &Timed) -> Change {
+    let (m1, m2) = (base.mean.as_secs_f64(), new.mean.as_secs_f64());
+    let (n1, n2) = (base.samples.max(1) as f64, new.samples.max(1) as f64);
+    let v1 = base.std_dev.as_secs_f64().powi(2) / n1;
+    let v2 = new.std_dev.as_secs_f64().powi(2) / n2;
+    let se = (v1 + v2).sqrt();
+
+    // Welch-Satterthwaite degrees of freedom, which are infinite if neither has any spread
+    let df =
+        (v1 + v2).powi(2) / (v1.powi(2) / (n1 - 1.0).max(1.0) + v2.powi(2) / (n2 - 1.0).max(1.0));
+    let margin = t_95(df) * se;
+
+    let pcnt = |d: f64| if m1 == 0.0 { 0.0 } else { 100.0 * d / m1 };
+    let diff = m2 - m1;
+    Change {
+        pcnt: pcnt(diff),
+        low: pcnt(diff - margin),
+        high: pcnt(diff + margin),
+    }
+}
+
+/// Two sided 95% point of Student's t distribution, from the Cornish-Fisher expansion around the
+/// normal distribution. Within 1% of the exact value from 3 degrees of freedom up
+#[allow(clippy::suboptimal_flops)]
+fn t_95(df: f64) -> f64 {
+    if !df.is_finite() {
+        return Z_95;
+    }
+    let z = Z_95;
+    let (z3, z5, z7) = (z.powi(3), z.powi(5), z.powi(7));
+    z + (z3 + z) / (4.0 * df)
+        + (5.0 * z5 + 16.0 * z3 + 3.0 * z) / (96.0 * df.powi(2))
+        + (3.0 * z7 + 19.0 * z5 + 17.0 * z3 - 15.0 * z) / (384.0 * df.powi(3))
+}
+
+fn git(args: &[&str]) -> io::Result<String> {
+    let out = Command::new("git").args(args).current_dir(ROOT).output()?;
+    if !out.status.success() {
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&out.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed(mean_us: u64, std_dev_us: u64, samples: usize) -> Timed {
+        Timed {
+            commit: String::from("abc1234"),
+            samples,
+            mean: Duration::from_micros(mean_us),
+            median: Duration::from_micros(mean_us),
+            std_dev: Duration::from_micros(std_dev_us),
+        }
+    }
+
+    #[test]
+    fn t_distribution() {
+        assert!((t_95(f64::INFINITY) - 1.96).abs() < 0.001);
+        assert!((t_95(30.0) - 2.042).abs() < 0.001);
+        assert!((t_95(10.0) - 2.228).abs() < 0.005);
+        assert!((t_95(3.0) - 3.182).abs() < 0.03);
+    }
+
+    #[test]
+    fn welch() {
+        let faster = change(&timed(100, 5, 100), &timed(80, 5, 100));
+        assert!((faster.pcnt + 20.0).abs() < 1e-9);
+        assert!(faster.low < -20.0 && faster.high > -20.0);
+        assert!(faster.significant());
+
+        // Too noisy to tell the two apart
+        let noisy = change(&timed(100, 50, 5), &timed(98, 50, 5));
+        assert!(noisy.low < 0.0 && noisy.high > 0.0);
+        assert!(!noisy.significant());
+
+        let exact = change(&timed(100, 0, 1), &timed(110, 0, 1));
+        assert!((exact.low - 10.0).abs() < 1e-9 && (exact.high - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn csv_report() {
+        let csv = "year,day,outcome,samples,outliers,min_ns,median_ns,mean_ns,std_dev_ns\n\
+                   2015,1,ok,40,2,900,1200,1010,20\n";
+        assert_eq!(
+            Some(Timed {
+                commit: String::new(),
+                samples: 40,
+                mean: Duration::from_nanos(1010),
+                median: Duration::from_nanos(1200),
+                std_dev: Duration::from_nanos(20),
+            }),
+            parse_csv(csv)
+        );
+        assert_eq!(None, parse_csv("year,day,outcome,samples\n2015,1,panic,\n"));
+        assert_eq!(None, parse_csv(""));
+    }
+}
@@ -0,0 +1,216 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::process::Command;
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+/// Package name used in the template, swapped out for the new day's name
+const TEMPLATE_NAME: &str = "day_2025_XX";
+
+/// Create `yyyy/day_dd` from the template folder & add it as a dependency of the runner
+///
+/// Refuses to touch a day that already has a folder, so no work can be lost. The day's crate
+/// includes its input, so the day is only added as a dependency once it has an `input.txt`, even
+/// if that has to be an empty one for now, otherwise the whole runner would stop building
+pub fn new_day(year: u16, day: u8) -> io::Result<()> {
+    let root = Path::new(ROOT);
+    let dir = root.join(format!("{year}/day_{day:02}"));
+    if dir.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{year}/day_{day:02} already exists"),
+        ));
+    }
+
+    // Read everything first so a missing template doesn't leave a half made day behind
+    let template = root.join("template");
+    let cargo_toml = fs::read_to_string(template.join("Cargo.toml"))?
+        .replace(TEMPLATE_NAME, &format!("day_{year}_{day:02}"));
+    let main_rs = fs::read_to_string(template.join("src/main.rs"))?;
+    let manifest = fs::read_to_string(root.join("Cargo.toml"))?;
+
+    // Made off to the side & moved into place in one go, for the same reason. The leading `.` keeps
+    // it out of the registry & the input downloads until then
+    let staging = root.join(format!("{year}/.day_{day:02}"));
+    if let Err(e) =
+        write_day(&staging, &cargo_toml, &main_rs).and_then(|()| fs::rename(&staging, &dir))
+    {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    println!("Created {year}/day_{day:02}");
+
+    let input = dir.join("src/input.txt");
+    if !download_input(&input) {
+        fs::write(&input, "")?;
+        eprintln!("Couldn't download the input for {year} day {day}, so it's empty for now.");
+        eprintln!(
+            "Fill in `src/input.txt`, or run `cargo aoc-inputs --year {year} --days {day} --force`"
+        );
+        eprintln!("once it can be downloaded, see the inputs folder for how to set that up");
+    }
+    fs::write(
+        root.join("Cargo.toml"),
+        add_dependency(&manifest, year, day),
+    )?;
+    println!("Run it with `cargo run --release -- run {year} {day}`");
+    Ok(())
+}
+
+/// Write out the template files for a new day, replacing anything left from a run that was stopped
+/// part way through
+fn write_day(dir: &Path, cargo_toml: &str, main_rs: &str) -> io::Result<()> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), cargo_toml)?;
+    fs::write(dir.join("src/main.rs"), main_rs)?;
+    fs::write(dir.join("src/test.txt"), "")
+}
+
+/// Try to fetch the new day's input, it's not a failure if this can't be done right now
+fn download_input(input: &Path) -> bool {
+    let _ = Command::new("cargo")
+        .arg("aoc-inputs")
+        .current_dir(ROOT)
+        .status();
+    input.exists()
+}
+
+/// Add the day to the dependencies in the root `Cargo.toml`
+///
+/// Days are listed in order under a heading for each year, newest year first, with days that
+/// haven't been written yet commented out. A commented out day is switched on in place, otherwise
+/// the day is slotted in with the rest of its year, or under a new heading for a new year
+fn add_dependency(manifest: &str, year: u16, day: u8) -> String {
+    let name = format!("day_{year}_{day:02}");
+    let dep = format!("{name} = {{ path = \"{year}/day_{day:02}\" }}");
+    let heading = format!("# {year}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+
+    if let Some(i) = lines
+        .iter()
+        .position(|l| dependency(l) == Some((year, day)))
+    {
+        lines[i] = &dep;
+    } else if let Some(i) = lines
+        .iter()
+        .rposition(|l| dependency(l).is_some_and(|(y, d)| y == year && d < day))
+    {
+        lines.insert(i + 1, &dep);
+    } else if let Some(i) = lines
+        .iter()
+        .position(|l| dependency(l).is_some_and(|(y, _)| y == year))
+    {
+        lines.insert(i, &dep);
+    } else {
+        // Headings are newest first, so the new year goes before the first older one
+        let at = lines
+            .iter()
+            .position(|l| {
+                l.strip_prefix("# ")
+                    .and_then(|y| y.parse::<u16>().ok())
+                    .is_some_and(|y| y < year)
+            })
+            .or_else(|| {
+                let deps = lines.iter().position(|l| *l == "[dependencies]")?;
+                lines[deps + 1..]
+                    .iter()
+                    .position(|l| l.starts_with('['))
+                    .map(|i| deps + 1 + i)
+            })
+            .unwrap_or(lines.len());
+        let section = [
+            &heading,
+            "# ===================================",
+            "",
+            &dep,
+            "",
+        ];
+        lines.splice(at..at, section);
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
+/// The (year, day) of a day dependency, whether it's commented out or not
+fn dependency(line: &str) -> Option<(u16, u8)> {
+    let line = line.trim_start_matches(['#', ' ']).strip_prefix("day_")?;
+    let (name, _) = line.split_once('=')?;
+    let (year, day) = name.trim().split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[dependencies]
+took = \"0.1.2\"
+
+# 2016
+# ===================================
+
+day_2016_01 = { path = \"2016/day_01\" }
+# day_2016_02 = { path = \"2016/day_02\" }
+
+# 2015
+# ===================================
+
+day_2015_03 = { path = \"2015/day_03\" }
+day_2015_07 = { path = \"2015/day_07\" }
+
+[features]
+";
+
+    #[test]
+    fn uncomments_day() {
+        let out = add_dependency(MANIFEST, 2016, 2);
+        assert!(out.contains("\nday_2016_02 = { path = \"2016/day_02\" }\n"));
+        assert!(!out.contains("# day_2016_02"));
+        assert_eq!(MANIFEST.lines().count(), out.lines().count());
+    }
+
+    #[test]
+    fn slots_day_into_year() {
+        let out = add_dependency(MANIFEST, 2015, 5);
+        assert!(
+            out.contains("2015/day_03\" }\nday_2015_05 = { path = \"2015/day_05\" }\nday_2015_07")
+        );
+
+        let out = add_dependency(MANIFEST, 2015, 1);
+        assert!(out.contains("\nday_2015_01 = { path = \"2015/day_01\" }\nday_2015_03"));
+    }
+
+    #[test]
+    fn new_year_section() {
+        let out = add_dependency(MANIFEST, 2017, 1);
+        assert!(out.contains(
+            "took = \"0.1.2\"\n\n# 2017\n# ===================================\n\nday_2017_01 = { path = \"2017/day_01\" }\n\n# 2016"
+        ));
+
+        let out = add_dependency(MANIFEST, 2014, 1);
+        assert!(out.ends_with(
+            "2015/day_07\" }\n\n# 2014\n# ===================================\n\nday_2014_01 = { path = \"2014/day_01\" }\n\n[features]\n"
+        ));
+    }
+
+    #[test]
+    fn replaces_leftover_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-rs-scaffold-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/stray.rs"), "").unwrap();
+        write_day(&dir, "[package]", "fn main() {}").unwrap();
+        let stray = dir.join("src/stray.rs").exists();
+        let main_rs = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        let test_txt = dir.join("src/test.txt").exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!stray);
+        assert_eq!("fn main() {}", main_rs);
+        assert!(test_txt);
+    }
+}